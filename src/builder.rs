@@ -16,9 +16,11 @@ use llvm_sys::core::{
     LLVMBuildStore, LLVMBuildSub, LLVMBuildSwitch, LLVMBuildTrunc, LLVMBuildTruncOrBitCast, LLVMBuildUDiv,
     LLVMBuildUIToFP, LLVMBuildURem, LLVMBuildUnreachable, LLVMBuildVAArg, LLVMBuildXor, LLVMBuildZExt,
     LLVMBuildZExtOrBitCast, LLVMClearInsertionPosition, LLVMDisposeBuilder, LLVMGetInsertBlock, LLVMInsertIntoBuilder,
-    LLVMInsertIntoBuilderWithName, LLVMPositionBuilder, LLVMPositionBuilderAtEnd, LLVMPositionBuilderBefore,
-    LLVMSetCleanup,
+    LLVMInsertIntoBuilderWithName, LLVMPointerType, LLVMPositionBuilder, LLVMPositionBuilderAtEnd,
+    LLVMPositionBuilderBefore, LLVMSetCleanup,
 };
+#[llvm_versions(9.0..=latest)]
+use llvm_sys::core::{LLVMAddGlobal, LLVMGetGlobalParent, LLVMGetNamedGlobal, LLVMIsConstant};
 #[llvm_versions(4.0..14.0)]
 use llvm_sys::core::{
     LLVMBuildCall, LLVMBuildGEP, LLVMBuildInBoundsGEP, LLVMBuildInvoke, LLVMBuildLoad, LLVMBuildPtrDiff,
@@ -39,8 +41,16 @@ use crate::basic_block::BasicBlock;
 use crate::context::AsContextRef;
 #[llvm_versions(7.0..=latest)]
 use crate::debug_info::DILocation;
+#[llvm_versions(9.0..=latest)]
+use crate::intrinsics::Intrinsic;
+#[llvm_versions(9.0..=latest)]
+use crate::module::Linkage;
 use crate::support::to_c_str;
 use crate::types::{AsTypeRef, BasicType, FloatMathType, IntMathType, PointerMathType, PointerType};
+#[llvm_versions(9.0..=latest)]
+use crate::types::{BasicTypeEnum, IntType};
+#[llvm_versions(9.0..=latest)]
+use crate::values::MetadataValue;
 use crate::values::{
     AggregateValue, AggregateValueEnum, AsValueRef, BasicMetadataValueEnum, BasicValue, BasicValueEnum, CallSiteValue,
     CallableValue, FloatMathValue, FunctionValue, GlobalValue, InstructionOpcode, InstructionValue, IntMathValue,
@@ -48,7 +58,7 @@ use crate::values::{
 };
 #[cfg(feature = "internal-getters")]
 use crate::LLVMReference;
-use crate::{AtomicOrdering, AtomicRMWBinOp, FloatPredicate, IntPredicate};
+use crate::{AddressSpace, AtomicOrdering, AtomicRMWBinOp, FloatPredicate, IntPredicate};
 
 use std::marker::PhantomData;
 
@@ -1308,6 +1318,41 @@ impl<'ctx> Builder<'ctx> {
         unsafe { PointerValue::new(value) }
     }
 
+    /// Builds an address space cast of `ptr_val` to a pointer to the same pointee type in
+    /// `address_space`, such as from a generic pointer to a shared memory one on GPU targets.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    /// use inkwell::AddressSpace;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("ascast");
+    /// let builder = context.create_builder();
+    /// let i32_ptr_type = context.i32_type().ptr_type(AddressSpace::NVPTX_GENERIC);
+    /// let fn_type = context.void_type().fn_type(&[i32_ptr_type.into()], false);
+    /// let fn_value = module.add_function("ascast", fn_type, None);
+    /// let ptr = fn_value.get_first_param().unwrap().into_pointer_value();
+    ///
+    /// builder.position_at_end(context.append_basic_block(fn_value, "entry"));
+    ///
+    /// let shared_ptr = builder.build_address_space_cast_to(ptr, AddressSpace::NVPTX_SHARED, "shared_ptr");
+    ///
+    /// assert_eq!(shared_ptr.get_type().get_address_space(), AddressSpace::Shared);
+    /// ```
+    pub fn build_address_space_cast_to(
+        &self,
+        ptr_val: PointerValue<'ctx>,
+        address_space: AddressSpace,
+        name: &str,
+    ) -> PointerValue<'ctx> {
+        let element_type = ptr_val.get_type().get_element_type();
+        let ptr_type = unsafe { PointerType::new(LLVMPointerType(element_type.as_type_ref(), address_space as u32)) };
+
+        self.build_address_space_cast(ptr_val, ptr_type, name)
+    }
+
     /// Builds a bitcast instruction. A bitcast reinterprets the bits of one value
     /// into a value of another type which has the same bit width.
     ///
@@ -2605,6 +2650,751 @@ impl<'ctx> Builder<'ctx> {
         unsafe { Ok(StructValue::new(val)) }
     }
 
+    /// Builds a call to [llvm.bswap](https://llvm.org/docs/LangRef.html#llvm-bswap-intrinsics), which
+    /// swaps the byte order of an integer (or vector of integers) whose bit width is a multiple of 16.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("bswap");
+    /// let builder = context.create_builder();
+    /// let i32_type = context.i32_type();
+    /// let fn_type = i32_type.fn_type(&[i32_type.into()], false);
+    /// let fn_value = module.add_function("bswap", fn_type, None);
+    /// let entry = context.append_basic_block(fn_value, "entry");
+    /// let i32_arg = fn_value.get_first_param().unwrap().into_int_value();
+    ///
+    /// builder.position_at_end(entry);
+    ///
+    /// let swapped = builder.build_bswap(i32_arg, "swapped").unwrap();
+    ///
+    /// builder.build_return(Some(&swapped));
+    /// ```
+    #[llvm_versions(9.0..=latest)]
+    pub fn build_bswap<T: IntMathValue<'ctx>>(&self, value: T, name: &str) -> Result<T, &'static str> {
+        self.build_unary_int_intrinsic("llvm.bswap", value, name)
+    }
+
+    /// Builds a call to [llvm.bitreverse](https://llvm.org/docs/LangRef.html#llvm-bitreverse-intrinsics),
+    /// which reverses the order of the bits of an integer (or vector of integers).
+    #[llvm_versions(9.0..=latest)]
+    pub fn build_bitreverse<T: IntMathValue<'ctx>>(&self, value: T, name: &str) -> Result<T, &'static str> {
+        self.build_unary_int_intrinsic("llvm.bitreverse", value, name)
+    }
+
+    /// Builds a call to [llvm.ctpop](https://llvm.org/docs/LangRef.html#llvm-ctpop-intrinsic), which
+    /// counts the number of bits set in an integer (or vector of integers).
+    #[llvm_versions(9.0..=latest)]
+    pub fn build_ctpop<T: IntMathValue<'ctx>>(&self, value: T, name: &str) -> Result<T, &'static str> {
+        self.build_unary_int_intrinsic("llvm.ctpop", value, name)
+    }
+
+    /// Builds a call to [llvm.ctlz](https://llvm.org/docs/LangRef.html#llvm-ctlz-intrinsic), which
+    /// counts the leading zero bits of an integer (or vector of integers).
+    ///
+    /// If `is_zero_undef` is true, the result is undefined when `value` is zero, which allows
+    /// some targets to use a cheaper instruction.
+    #[llvm_versions(9.0..=latest)]
+    pub fn build_ctlz<T: IntMathValue<'ctx>>(
+        &self,
+        value: T,
+        is_zero_undef: bool,
+        name: &str,
+    ) -> Result<T, &'static str> {
+        self.build_count_zeros_intrinsic("llvm.ctlz", value, is_zero_undef, name)
+    }
+
+    /// Builds a call to [llvm.cttz](https://llvm.org/docs/LangRef.html#llvm-cttz-intrinsic), which
+    /// counts the trailing zero bits of an integer (or vector of integers).
+    ///
+    /// If `is_zero_undef` is true, the result is undefined when `value` is zero, which allows
+    /// some targets to use a cheaper instruction.
+    #[llvm_versions(9.0..=latest)]
+    pub fn build_cttz<T: IntMathValue<'ctx>>(
+        &self,
+        value: T,
+        is_zero_undef: bool,
+        name: &str,
+    ) -> Result<T, &'static str> {
+        self.build_count_zeros_intrinsic("llvm.cttz", value, is_zero_undef, name)
+    }
+
+    /// Builds a call to [llvm.fshl](https://llvm.org/docs/LangRef.html#llvm-fshl-intrinsic), a funnel
+    /// shift left. `hi` and `lo` are concatenated, shifted left by `shift` modulo the bit width, and
+    /// the most significant half is returned. Passing the same value as `hi` and `lo` is a rotate left.
+    #[llvm_versions(9.0..=latest)]
+    pub fn build_fshl<T: IntMathValue<'ctx>>(&self, hi: T, lo: T, shift: T, name: &str) -> Result<T, &'static str> {
+        self.build_funnel_shift_intrinsic("llvm.fshl", hi, lo, shift, name)
+    }
+
+    /// Builds a call to [llvm.fshr](https://llvm.org/docs/LangRef.html#llvm-fshr-intrinsic), a funnel
+    /// shift right. `hi` and `lo` are concatenated, shifted right by `shift` modulo the bit width, and
+    /// the least significant half is returned. Passing the same value as `hi` and `lo` is a rotate right.
+    #[llvm_versions(9.0..=latest)]
+    pub fn build_fshr<T: IntMathValue<'ctx>>(&self, hi: T, lo: T, shift: T, name: &str) -> Result<T, &'static str> {
+        self.build_funnel_shift_intrinsic("llvm.fshr", hi, lo, shift, name)
+    }
+
+    /// Builds a call to [llvm.expect](https://llvm.org/docs/LangRef.html#llvm-expect-intrinsic), which
+    /// returns `value` unchanged but tells the optimizer that it is most likely equal to `expected`.
+    /// Branching on the result lets block placement favor the expected path, which is how `likely()`
+    /// and `unlikely()` style hints are usually lowered.
+    ///
+    /// `expected` must be a constant.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("expect");
+    /// let builder = context.create_builder();
+    /// let bool_type = context.bool_type();
+    /// let void_type = context.void_type();
+    /// let fn_type = void_type.fn_type(&[bool_type.into()], false);
+    /// let fn_value = module.add_function("unlikely", fn_type, None);
+    /// let entry = context.append_basic_block(fn_value, "entry");
+    /// let then_block = context.append_basic_block(fn_value, "then");
+    /// let else_block = context.append_basic_block(fn_value, "else");
+    /// let cond = fn_value.get_first_param().unwrap().into_int_value();
+    ///
+    /// builder.position_at_end(entry);
+    ///
+    /// let unlikely = builder.build_expect(cond, bool_type.const_zero(), "unlikely").unwrap();
+    ///
+    /// builder.build_conditional_branch(unlikely, then_block, else_block);
+    /// ```
+    #[llvm_versions(9.0..=latest)]
+    pub fn build_expect<T: IntMathValue<'ctx>>(&self, value: T, expected: T, name: &str) -> Result<T, &'static str> {
+        if unsafe { LLVMIsConstant(expected.as_value_ref()) } == 0 {
+            return Err("The expected value must be a constant.");
+        }
+
+        let value = value.as_basic_value_enum();
+        let call = self.build_intrinsic_call(
+            "llvm.expect",
+            &[value.get_type()],
+            &[value.into(), expected.as_basic_value_enum().into()],
+            name,
+        )?;
+
+        Ok(T::new(call.as_value_ref()))
+    }
+
+    /// Builds a call to [llvm.assume](https://llvm.org/docs/LangRef.html#llvm-assume-intrinsic), which
+    /// lets the optimizer assume that `condition` (an `i1`) is always true. If it is ever false, the
+    /// behavior is undefined.
+    #[llvm_versions(9.0..=latest)]
+    pub fn build_assume(&self, condition: IntValue<'ctx>) -> Result<InstructionValue<'ctx>, &'static str> {
+        if condition.get_type().get_bit_width() != 1 {
+            return Err("The condition of an assume must be an i1.");
+        }
+
+        let call = self.build_intrinsic_call("llvm.assume", &[], &[condition.into()], "")?;
+
+        unsafe { Ok(InstructionValue::new(call.as_value_ref())) }
+    }
+
+    /// Tells the optimizer that `ptr` is aligned to at least `alignment` bytes, which for instance
+    /// allows the loop vectorizer to use aligned vector loads and stores.
+    ///
+    /// The LLVM C API cannot attach operand bundles, so instead of `"align"(ptr, alignment)` this
+    /// builds the equivalent `assume((ptrtoint(ptr) & (alignment - 1)) == 0)`, which LLVM canonicalizes
+    /// into the bundle form.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    /// use inkwell::AddressSpace;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("assume");
+    /// let builder = context.create_builder();
+    /// let f32_ptr_type = context.f32_type().ptr_type(AddressSpace::Generic);
+    /// let fn_type = context.void_type().fn_type(&[f32_ptr_type.into()], false);
+    /// let fn_value = module.add_function("kernel", fn_type, None);
+    /// let entry = context.append_basic_block(fn_value, "entry");
+    /// let ptr = fn_value.get_first_param().unwrap().into_pointer_value();
+    ///
+    /// builder.position_at_end(entry);
+    /// builder.build_assume_aligned(ptr, 64).unwrap();
+    /// builder.build_return(None);
+    /// ```
+    #[llvm_versions(9.0..=latest)]
+    pub fn build_assume_aligned(
+        &self,
+        ptr: PointerValue<'ctx>,
+        alignment: u32,
+    ) -> Result<InstructionValue<'ctx>, &'static str> {
+        if !alignment.is_power_of_two() {
+            return Err("The alignment must be a power of 2.");
+        }
+
+        let i64_type = ptr.get_type().get_context().i64_type();
+        let ptr_int = self.build_ptr_to_int(ptr, i64_type, "");
+        let mask = i64_type.const_int(alignment as u64 - 1, false);
+        let masked = self.build_and(ptr_int, mask, "");
+        let is_aligned = self.build_int_compare(IntPredicate::EQ, masked, i64_type.const_zero(), "");
+
+        self.build_assume(is_aligned)
+    }
+
+    /// Tells the optimizer that `ptr` is never null.
+    ///
+    /// Like [`Builder::build_assume_aligned`], this builds the condition form
+    /// `assume(ptr != null)` rather than a `"nonnull"(ptr)` operand bundle.
+    #[llvm_versions(9.0..=latest)]
+    pub fn build_assume_nonnull(&self, ptr: PointerValue<'ctx>) -> Result<InstructionValue<'ctx>, &'static str> {
+        let is_not_null = self.build_is_not_null(ptr, "");
+
+        self.build_assume(is_not_null)
+    }
+
+    /// Builds a call to [llvm.lifetime.start](https://llvm.org/docs/LangRef.html#llvm-lifetime-start-intrinsic),
+    /// marking the start of the lifetime of the `size` bytes of the stack slot `ptr` points to. `ptr`
+    /// should be an alloca or a bitcast of one. Stack slots whose lifetimes don't overlap can be
+    /// assigned to the same stack location by the backend.
+    ///
+    /// A `size` of `u64::MAX` (which is `-1` in LLVM) marks the whole object.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("lifetime");
+    /// let builder = context.create_builder();
+    /// let array_type = context.i8_type().array_type(256);
+    /// let fn_type = context.void_type().fn_type(&[], false);
+    /// let fn_value = module.add_function("scoped", fn_type, None);
+    /// let entry = context.append_basic_block(fn_value, "entry");
+    ///
+    /// builder.position_at_end(entry);
+    ///
+    /// let buffer = builder.build_alloca(array_type, "buffer");
+    ///
+    /// builder.build_lifetime_start(buffer, 256).unwrap();
+    /// // ... use buffer ...
+    /// builder.build_lifetime_end(buffer, 256).unwrap();
+    /// builder.build_return(None);
+    /// ```
+    #[llvm_versions(9.0..=latest)]
+    pub fn build_lifetime_start(
+        &self,
+        ptr: PointerValue<'ctx>,
+        size: u64,
+    ) -> Result<InstructionValue<'ctx>, &'static str> {
+        self.build_lifetime_intrinsic("llvm.lifetime.start", ptr, size)
+    }
+
+    /// Builds a call to [llvm.lifetime.end](https://llvm.org/docs/LangRef.html#llvm-lifetime-end-intrinsic),
+    /// marking the end of the lifetime of the `size` bytes of the stack slot `ptr` points to.
+    /// See [`Builder::build_lifetime_start`].
+    #[llvm_versions(9.0..=latest)]
+    pub fn build_lifetime_end(
+        &self,
+        ptr: PointerValue<'ctx>,
+        size: u64,
+    ) -> Result<InstructionValue<'ctx>, &'static str> {
+        self.build_lifetime_intrinsic("llvm.lifetime.end", ptr, size)
+    }
+
+    #[llvm_versions(9.0..=latest)]
+    fn build_lifetime_intrinsic(
+        &self,
+        intrinsic_name: &str,
+        ptr: PointerValue<'ctx>,
+        size: u64,
+    ) -> Result<InstructionValue<'ctx>, &'static str> {
+        let size = ptr.get_type().get_context().i64_type().const_int(size, false);
+        let call =
+            self.build_intrinsic_call(intrinsic_name, &[ptr.get_type().into()], &[size.into(), ptr.into()], "")?;
+
+        unsafe { Ok(InstructionValue::new(call.as_value_ref())) }
+    }
+
+    /// Builds a call to [llvm.prefetch](https://llvm.org/docs/LangRef.html#llvm-prefetch-intrinsic), a
+    /// hint to the code generator to insert a prefetch instruction for `ptr` if the target supports it.
+    /// Prefetches have no effect on the behavior of the program.
+    ///
+    /// `is_write` selects a prefetch for writing rather than reading. `locality` ranges from `0` (no
+    /// temporal locality) to `3` (extremely local, keep in cache). `is_data_cache` selects the data
+    /// cache rather than the instruction cache.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    /// use inkwell::AddressSpace;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("prefetch");
+    /// let builder = context.create_builder();
+    /// let i8_ptr_type = context.i8_type().ptr_type(AddressSpace::Generic);
+    /// let fn_type = context.void_type().fn_type(&[i8_ptr_type.into()], false);
+    /// let fn_value = module.add_function("prefetch", fn_type, None);
+    /// let entry = context.append_basic_block(fn_value, "entry");
+    /// let ptr = fn_value.get_first_param().unwrap().into_pointer_value();
+    ///
+    /// builder.position_at_end(entry);
+    /// builder.build_prefetch(ptr, false, 3, true).unwrap();
+    /// builder.build_return(None);
+    /// ```
+    #[llvm_versions(9.0..=latest)]
+    pub fn build_prefetch(
+        &self,
+        ptr: PointerValue<'ctx>,
+        is_write: bool,
+        locality: u32,
+        is_data_cache: bool,
+    ) -> Result<InstructionValue<'ctx>, &'static str> {
+        if locality > 3 {
+            return Err("The locality of a prefetch must be between 0 and 3.");
+        }
+
+        let i32_type = ptr.get_type().get_context().i32_type();
+        let args = [
+            ptr.into(),
+            i32_type.const_int(is_write as u64, false).into(),
+            i32_type.const_int(locality as u64, false).into(),
+            i32_type.const_int(is_data_cache as u64, false).into(),
+        ];
+        let call = self.build_intrinsic_call("llvm.prefetch", &[ptr.get_type().into()], &args, "")?;
+
+        unsafe { Ok(InstructionValue::new(call.as_value_ref())) }
+    }
+
+    /// Builds a call to [llvm.trap](https://llvm.org/docs/LangRef.html#llvm-trap-intrinsic), which
+    /// lowers to a target dependent trap instruction (or a call to `abort()` if the target has none).
+    ///
+    /// The call is not a terminator, so it is usually followed by [`Builder::build_unreachable`].
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("trap");
+    /// let builder = context.create_builder();
+    /// let fn_type = context.void_type().fn_type(&[], false);
+    /// let fn_value = module.add_function("panic", fn_type, None);
+    /// let entry = context.append_basic_block(fn_value, "entry");
+    ///
+    /// builder.position_at_end(entry);
+    /// builder.build_trap().unwrap();
+    /// builder.build_unreachable();
+    /// ```
+    #[llvm_versions(9.0..=latest)]
+    pub fn build_trap(&self) -> Result<InstructionValue<'ctx>, &'static str> {
+        let call = self.build_intrinsic_call("llvm.trap", &[], &[], "")?;
+
+        unsafe { Ok(InstructionValue::new(call.as_value_ref())) }
+    }
+
+    /// Builds a call to [llvm.debugtrap](https://llvm.org/docs/LangRef.html#llvm-debugtrap-intrinsic),
+    /// which lowers to an instruction that causes an execution trap in the debugger (ie `int3` on x86).
+    /// Execution continues after the call once the debugger resumes.
+    #[llvm_versions(9.0..=latest)]
+    pub fn build_debugtrap(&self) -> Result<InstructionValue<'ctx>, &'static str> {
+        let call = self.build_intrinsic_call("llvm.debugtrap", &[], &[], "")?;
+
+        unsafe { Ok(InstructionValue::new(call.as_value_ref())) }
+    }
+
+    /// Builds a call to [llvm.ubsantrap](https://llvm.org/docs/LangRef.html#llvm-ubsantrap-intrinsic),
+    /// which behaves like [`Builder::build_trap`] but encodes `failure_kind` into the trap instruction
+    /// where the target supports it, so the reason for the trap can be recovered.
+    #[llvm_versions(12.0..=latest)]
+    pub fn build_ubsantrap(&self, failure_kind: u8) -> Result<InstructionValue<'ctx>, &'static str> {
+        let context = self
+            .get_insert_block()
+            .ok_or("The builder must be positioned in a basic block to build an intrinsic call.")?
+            .get_context();
+        let failure_kind = context.i8_type().const_int(failure_kind as u64, false);
+        let call = self.build_intrinsic_call("llvm.ubsantrap", &[], &[failure_kind.into()], "")?;
+
+        unsafe { Ok(InstructionValue::new(call.as_value_ref())) }
+    }
+
+    /// Builds a call to [llvm.returnaddress](https://llvm.org/docs/LangRef.html#llvm-returnaddress-intrinsic),
+    /// which returns an `i8*` to the return address of the current function (`level` 0), or of one of
+    /// its callers (`level` > 0). Non zero levels are not supported on all targets, in which case null
+    /// is returned.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    /// use inkwell::AddressSpace;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("backtrace");
+    /// let builder = context.create_builder();
+    /// let i8_ptr_type = context.i8_type().ptr_type(AddressSpace::Generic);
+    /// let fn_type = i8_ptr_type.fn_type(&[], false);
+    /// let fn_value = module.add_function("caller", fn_type, None);
+    /// let entry = context.append_basic_block(fn_value, "entry");
+    ///
+    /// builder.position_at_end(entry);
+    ///
+    /// let return_address = builder.build_return_address(0, "ret_addr").unwrap();
+    ///
+    /// builder.build_return(Some(&return_address));
+    /// ```
+    #[llvm_versions(9.0..=latest)]
+    pub fn build_return_address(&self, level: u32, name: &str) -> Result<PointerValue<'ctx>, &'static str> {
+        self.build_frame_intrinsic("llvm.returnaddress", level, name)
+    }
+
+    /// Builds a call to [llvm.frameaddress](https://llvm.org/docs/LangRef.html#llvm-frameaddress-intrinsic),
+    /// which returns an `i8*` to the frame address of the current function (`level` 0), or of one of
+    /// its callers (`level` > 0). Non zero levels are not supported on all targets, in which case null
+    /// is returned.
+    #[llvm_versions(9.0..=latest)]
+    pub fn build_frame_address(&self, level: u32, name: &str) -> Result<PointerValue<'ctx>, &'static str> {
+        self.build_frame_intrinsic("llvm.frameaddress", level, name)
+    }
+
+    #[llvm_versions(9.0..=latest)]
+    fn build_frame_intrinsic(
+        &self,
+        intrinsic_name: &str,
+        level: u32,
+        name: &str,
+    ) -> Result<PointerValue<'ctx>, &'static str> {
+        let context = self
+            .get_insert_block()
+            .ok_or("The builder must be positioned in a basic block to build an intrinsic call.")?
+            .get_context();
+        let i8_ptr_type = context.i8_type().ptr_type(AddressSpace::Generic);
+        let level = context.i32_type().const_int(level as u64, false);
+        let call = self.build_intrinsic_call(intrinsic_name, &[i8_ptr_type.into()], &[level.into()], name)?;
+
+        unsafe { Ok(PointerValue::new(call.as_value_ref())) }
+    }
+
+    /// Builds a call to [llvm.stacksave](https://llvm.org/docs/LangRef.html#llvm-stacksave-intrinsic),
+    /// which returns an opaque pointer to the current state of the function stack. Restoring it with
+    /// [`Builder::build_stack_restore`] frees everything allocated with dynamic allocas in the meantime,
+    /// which is how variable length arrays are released on scope exit.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("vla");
+    /// let builder = context.create_builder();
+    /// let i32_type = context.i32_type();
+    /// let fn_type = context.void_type().fn_type(&[i32_type.into()], false);
+    /// let fn_value = module.add_function("vla", fn_type, None);
+    /// let entry = context.append_basic_block(fn_value, "entry");
+    /// let len = fn_value.get_first_param().unwrap().into_int_value();
+    ///
+    /// builder.position_at_end(entry);
+    ///
+    /// let stack = builder.build_stack_save("stack").unwrap();
+    /// let array = builder.build_array_alloca(i32_type, len, "array");
+    ///
+    /// // ... use array ...
+    ///
+    /// builder.build_stack_restore(stack).unwrap();
+    /// builder.build_return(None);
+    /// ```
+    #[llvm_versions(9.0..=latest)]
+    pub fn build_stack_save(&self, name: &str) -> Result<PointerValue<'ctx>, &'static str> {
+        let call = self.build_intrinsic_call("llvm.stacksave", &[], &[], name)?;
+
+        unsafe { Ok(PointerValue::new(call.as_value_ref())) }
+    }
+
+    /// Builds a call to [llvm.stackrestore](https://llvm.org/docs/LangRef.html#llvm-stackrestore-intrinsic),
+    /// which restores the function stack to a state previously returned by [`Builder::build_stack_save`].
+    #[llvm_versions(9.0..=latest)]
+    pub fn build_stack_restore(&self, stack: PointerValue<'ctx>) -> Result<InstructionValue<'ctx>, &'static str> {
+        let call = self.build_intrinsic_call("llvm.stackrestore", &[stack.get_type().into()], &[stack.into()], "")?;
+
+        unsafe { Ok(InstructionValue::new(call.as_value_ref())) }
+    }
+
+    /// Builds a call to [llvm.thread.pointer](https://llvm.org/docs/LangRef.html#llvm-thread-pointer-intrinsic),
+    /// which returns an `i8*` to the thread pointer (the base of the thread local storage area).
+    /// Only some targets, such as AArch64, are able to lower it.
+    #[llvm_versions(9.0..=latest)]
+    pub fn build_thread_pointer(&self, name: &str) -> Result<PointerValue<'ctx>, &'static str> {
+        let call = self.build_intrinsic_call("llvm.thread.pointer", &[], &[], name)?;
+
+        unsafe { Ok(PointerValue::new(call.as_value_ref())) }
+    }
+
+    /// Builds a call to [llvm.read_register](https://llvm.org/docs/LangRef.html#llvm-read-register-llvm-read-volatile-register-and-llvm-write-register-intrinsics),
+    /// which reads the named register (ie `"sp"`) as a value of type `int_type`. Only non allocatable
+    /// registers such as the stack pointer are supported, and the set of valid names is target dependent.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("registers");
+    /// let builder = context.create_builder();
+    /// let i64_type = context.i64_type();
+    /// let fn_type = i64_type.fn_type(&[], false);
+    /// let fn_value = module.add_function("get_sp", fn_type, None);
+    /// let entry = context.append_basic_block(fn_value, "entry");
+    ///
+    /// builder.position_at_end(entry);
+    ///
+    /// let sp = builder.build_read_register("rsp", i64_type, "sp").unwrap();
+    ///
+    /// builder.build_return(Some(&sp));
+    /// ```
+    #[llvm_versions(9.0..=latest)]
+    pub fn build_read_register(
+        &self,
+        register: &str,
+        int_type: IntType<'ctx>,
+        name: &str,
+    ) -> Result<IntValue<'ctx>, &'static str> {
+        let register = self.register_metadata(register)?;
+        let call = self.build_intrinsic_call("llvm.read_register", &[int_type.into()], &[register.into()], name)?;
+
+        unsafe { Ok(IntValue::new(call.as_value_ref())) }
+    }
+
+    /// Builds a call to [llvm.write_register](https://llvm.org/docs/LangRef.html#llvm-read-register-llvm-read-volatile-register-and-llvm-write-register-intrinsics),
+    /// which writes `value` to the named register. See [`Builder::build_read_register`].
+    #[llvm_versions(9.0..=latest)]
+    pub fn build_write_register(
+        &self,
+        register: &str,
+        value: IntValue<'ctx>,
+    ) -> Result<InstructionValue<'ctx>, &'static str> {
+        let register = self.register_metadata(register)?;
+        let call = self.build_intrinsic_call(
+            "llvm.write_register",
+            &[value.get_type().into()],
+            &[register.into(), value.into()],
+            "",
+        )?;
+
+        unsafe { Ok(InstructionValue::new(call.as_value_ref())) }
+    }
+
+    /// The register intrinsics take the name of the register as a metadata tuple holding a string.
+    #[llvm_versions(9.0..=latest)]
+    fn register_metadata(&self, register: &str) -> Result<MetadataValue<'ctx>, &'static str> {
+        let context = self
+            .get_insert_block()
+            .ok_or("The builder must be positioned in a basic block to build an intrinsic call.")?
+            .get_context();
+
+        Ok(context.metadata_node(&[context.metadata_string(register).into()]))
+    }
+
+    /// Builds a call to [llvm.readcyclecounter](https://llvm.org/docs/LangRef.html#llvm-readcyclecounter-intrinsic),
+    /// which returns an `i64` reading of the processor cycle counter (ie `rdtsc` on x86). Targets
+    /// without a cycle counter return zero.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("profile");
+    /// let builder = context.create_builder();
+    /// let i64_type = context.i64_type();
+    /// let fn_type = i64_type.fn_type(&[], false);
+    /// let fn_value = module.add_function("elapsed", fn_type, None);
+    /// let entry = context.append_basic_block(fn_value, "entry");
+    ///
+    /// builder.position_at_end(entry);
+    ///
+    /// let start = builder.build_read_cycle_counter("start").unwrap();
+    /// // ... code being measured ...
+    /// let end = builder.build_read_cycle_counter("end").unwrap();
+    /// let elapsed = builder.build_int_sub(end, start, "elapsed");
+    ///
+    /// builder.build_return(Some(&elapsed));
+    /// ```
+    #[llvm_versions(9.0..=latest)]
+    pub fn build_read_cycle_counter(&self, name: &str) -> Result<IntValue<'ctx>, &'static str> {
+        let call = self.build_intrinsic_call("llvm.readcyclecounter", &[], &[], name)?;
+
+        unsafe { Ok(IntValue::new(call.as_value_ref())) }
+    }
+
+    /// Builds a call to `llvm.instrprof.increment`, which bumps counter `index` of the
+    /// `num_counters` profile counters of `function`. The `hash` identifies the shape of the
+    /// instrumented control flow, so stale profiles can be detected. The calls are turned into
+    /// actual counter updates by the `instrprof` pass, see `Module::run_passes`.
+    ///
+    /// The function name is referenced through a private `__profn_<name>` global, which is
+    /// created in the function's module the first time it is needed.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("coverage");
+    /// let builder = context.create_builder();
+    /// let fn_type = context.void_type().fn_type(&[], false);
+    /// let fn_value = module.add_function("covered", fn_type, None);
+    /// let entry = context.append_basic_block(fn_value, "entry");
+    ///
+    /// builder.position_at_end(entry);
+    /// builder.build_instrprof_increment(fn_value, 0x1234, 1, 0).unwrap();
+    /// builder.build_return(None);
+    /// ```
+    #[llvm_versions(9.0..=latest)]
+    pub fn build_instrprof_increment(
+        &self,
+        function: FunctionValue<'ctx>,
+        hash: u64,
+        num_counters: u32,
+        index: u32,
+    ) -> Result<InstructionValue<'ctx>, &'static str> {
+        if index >= num_counters {
+            return Err("The counter index must be less than the number of counters.");
+        }
+
+        let context = function.get_type().get_context();
+        let i8_ptr_type = context.i8_type().ptr_type(AddressSpace::Generic);
+        let name_var = format!("__profn_{}", function.get_name().to_string_lossy());
+        let c_name_var = to_c_str(&name_var);
+
+        let name_global = unsafe {
+            let module = LLVMGetGlobalParent(function.as_value_ref());
+            let existing = LLVMGetNamedGlobal(module, c_name_var.as_ptr());
+
+            if existing.is_null() {
+                let name = context.const_string(function.get_name().to_bytes(), false);
+                let global = GlobalValue::new(LLVMAddGlobal(
+                    module,
+                    name.get_type().as_type_ref(),
+                    c_name_var.as_ptr(),
+                ));
+
+                global.set_initializer(&name);
+                global.set_constant(true);
+                global.set_linkage(Linkage::Private);
+                global
+            } else {
+                GlobalValue::new(existing)
+            }
+        };
+
+        let args = &[
+            name_global.as_pointer_value().const_cast(i8_ptr_type).into(),
+            context.i64_type().const_int(hash, false).into(),
+            context.i32_type().const_int(num_counters as u64, false).into(),
+            context.i32_type().const_int(index as u64, false).into(),
+        ];
+        let call = self.build_intrinsic_call("llvm.instrprof.increment", &[], args, "")?;
+
+        unsafe { Ok(InstructionValue::new(call.as_value_ref())) }
+    }
+
+    #[llvm_versions(9.0..=latest)]
+    fn build_unary_int_intrinsic<T: IntMathValue<'ctx>>(
+        &self,
+        intrinsic_name: &str,
+        value: T,
+        name: &str,
+    ) -> Result<T, &'static str> {
+        let value = value.as_basic_value_enum();
+        let call = self.build_intrinsic_call(intrinsic_name, &[value.get_type()], &[value.into()], name)?;
+
+        Ok(T::new(call.as_value_ref()))
+    }
+
+    #[llvm_versions(9.0..=latest)]
+    fn build_count_zeros_intrinsic<T: IntMathValue<'ctx>>(
+        &self,
+        intrinsic_name: &str,
+        value: T,
+        is_zero_undef: bool,
+        name: &str,
+    ) -> Result<T, &'static str> {
+        let value = value.as_basic_value_enum();
+        let is_zero_undef = self
+            .get_insert_block()
+            .ok_or("The builder must be positioned in a basic block to build an intrinsic call.")?
+            .get_context()
+            .bool_type()
+            .const_int(is_zero_undef as u64, false);
+        let call = self.build_intrinsic_call(
+            intrinsic_name,
+            &[value.get_type()],
+            &[value.into(), is_zero_undef.into()],
+            name,
+        )?;
+
+        Ok(T::new(call.as_value_ref()))
+    }
+
+    #[llvm_versions(9.0..=latest)]
+    fn build_funnel_shift_intrinsic<T: IntMathValue<'ctx>>(
+        &self,
+        intrinsic_name: &str,
+        hi: T,
+        lo: T,
+        shift: T,
+        name: &str,
+    ) -> Result<T, &'static str> {
+        let hi = hi.as_basic_value_enum();
+        let call = self.build_intrinsic_call(
+            intrinsic_name,
+            &[hi.get_type()],
+            &[
+                hi.into(),
+                lo.as_basic_value_enum().into(),
+                shift.as_basic_value_enum().into(),
+            ],
+            name,
+        )?;
+
+        Ok(T::new(call.as_value_ref()))
+    }
+
+    /// Builds a call to the intrinsic named `intrinsic_name`, declaring it in the module of the function
+    /// the builder is positioned in if necessary. Overloaded intrinsics need `overload_types` to pick
+    /// the overload; it should be empty for all others.
+    #[llvm_versions(9.0..=latest)]
+    fn build_intrinsic_call(
+        &self,
+        intrinsic_name: &str,
+        overload_types: &[BasicTypeEnum<'ctx>],
+        args: &[BasicMetadataValueEnum<'ctx>],
+        name: &str,
+    ) -> Result<CallSiteValue<'ctx>, &'static str> {
+        let intrinsic = Intrinsic::find(intrinsic_name).ok_or("This LLVM version does not provide the intrinsic.")?;
+        let function = self
+            .get_insert_block()
+            .and_then(|block| block.get_parent())
+            .ok_or("The builder must be positioned in a function to build an intrinsic call.")?;
+        // Some intrinsics only became overloaded in later LLVM versions, and non-overloaded
+        // intrinsics would otherwise get a bogus type suffix appended to their name
+        let overload_types = if intrinsic.is_overloaded() { overload_types } else { &[] };
+        let declaration =
+            unsafe { intrinsic.get_declaration_in(LLVMGetGlobalParent(function.as_value_ref()), overload_types) }
+                .ok_or("Could not declare the intrinsic for the given types.")?;
+
+        Ok(self.build_call(declaration, args, name))
+    }
+
     /// Set the debug info source location of the instruction currently pointed at by the builder
     #[llvm_versions(7.0..=latest)]
     pub fn set_current_debug_location(&self, context: impl AsContextRef<'ctx>, location: DILocation<'ctx>) {
@@ -517,7 +517,9 @@ impl Context {
         self.context.create_module_from_ir(memory_buffer)
     }
 
-    /// Creates a inline asm function pointer.
+    /// Creates a inline asm function pointer. The `dialect` selects between AT&T and Intel
+    /// syntax, and `can_throw` marks the assembly as possibly unwinding. LLVM does not check
+    /// `constraints` here; use `FunctionType::verify_inline_asm_constraints` to validate them first.
     ///
     /// # Example
     /// ```no_run
@@ -1351,7 +1353,9 @@ impl<'ctx> ContextRef<'ctx> {
         self.context.create_module_from_ir(memory_buffer)
     }
 
-    /// Creates a inline asm function pointer.
+    /// Creates a inline asm function pointer. The `dialect` selects between AT&T and Intel
+    /// syntax, and `can_throw` marks the assembly as possibly unwinding. LLVM does not check
+    /// `constraints` here; use `FunctionType::verify_inline_asm_constraints` to validate them first.
     ///
     /// # Example
     /// ```no_run
@@ -120,14 +120,14 @@ use llvm_sys::debuginfo::LLVMTemporaryMDNode;
 use llvm_sys::debuginfo::{LLVMCreateDIBuilder, LLVMCreateDIBuilderDisallowUnresolved};
 use llvm_sys::debuginfo::{
     LLVMDIBuilderCreateArrayType, LLVMDIBuilderCreateAutoVariable, LLVMDIBuilderCreateBasicType,
-    LLVMDIBuilderCreateCompileUnit, LLVMDIBuilderCreateDebugLocation, LLVMDIBuilderCreateExpression,
-    LLVMDIBuilderCreateFile, LLVMDIBuilderCreateFunction, LLVMDIBuilderCreateLexicalBlock,
-    LLVMDIBuilderCreateMemberType, LLVMDIBuilderCreateNameSpace, LLVMDIBuilderCreateParameterVariable,
-    LLVMDIBuilderCreatePointerType, LLVMDIBuilderCreateReferenceType, LLVMDIBuilderCreateStructType,
-    LLVMDIBuilderCreateSubroutineType, LLVMDIBuilderCreateUnionType, LLVMDIBuilderFinalize,
-    LLVMDIBuilderGetOrCreateSubrange, LLVMDIBuilderInsertDbgValueBefore, LLVMDIBuilderInsertDeclareAtEnd,
-    LLVMDIBuilderInsertDeclareBefore, LLVMDILocationGetColumn, LLVMDILocationGetLine, LLVMDILocationGetScope,
-    LLVMDITypeGetAlignInBits, LLVMDITypeGetOffsetInBits, LLVMDITypeGetSizeInBits,
+    LLVMDIBuilderCreateCompileUnit, LLVMDIBuilderCreateDebugLocation, LLVMDIBuilderCreateEnumerationType,
+    LLVMDIBuilderCreateEnumerator, LLVMDIBuilderCreateExpression, LLVMDIBuilderCreateFile, LLVMDIBuilderCreateFunction,
+    LLVMDIBuilderCreateLexicalBlock, LLVMDIBuilderCreateMemberType, LLVMDIBuilderCreateNameSpace,
+    LLVMDIBuilderCreateParameterVariable, LLVMDIBuilderCreatePointerType, LLVMDIBuilderCreateReferenceType,
+    LLVMDIBuilderCreateStructType, LLVMDIBuilderCreateSubroutineType, LLVMDIBuilderCreateUnionType,
+    LLVMDIBuilderFinalize, LLVMDIBuilderGetOrCreateSubrange, LLVMDIBuilderInsertDbgValueBefore,
+    LLVMDIBuilderInsertDeclareAtEnd, LLVMDIBuilderInsertDeclareBefore, LLVMDILocationGetColumn, LLVMDILocationGetLine,
+    LLVMDILocationGetScope, LLVMDITypeGetAlignInBits, LLVMDITypeGetOffsetInBits, LLVMDITypeGetSizeInBits,
 };
 #[llvm_versions(8.0..=latest)]
 use llvm_sys::debuginfo::{LLVMDIBuilderCreateConstantValueExpression, LLVMDIBuilderCreateGlobalVariableExpression};
@@ -255,6 +255,8 @@ impl<'ctx> DebugInfoBuilder<'ctx> {
     /// * `dwo_id` - The DWOId if this is a split skeleton compile unit.
     /// * `split_debug_inlining` - Whether to emit inline debug info.
     /// * `debug_info_for_profiling` - Whether to emit extra debug info for profile collection.
+    /// * `sysroot` - The clang system root, the value of clang's `-isysroot`.
+    /// * `sdk` - The SDK name, such as `MacOSX.sdk`.
     fn create_compile_unit(
         &self,
         language: DWARFSourceLanguage,
@@ -571,6 +573,54 @@ impl<'ctx> DebugInfoBuilder<'ctx> {
         }
     }
 
+    /// Create an enumerator, a single named value of an enumeration type.
+    pub fn create_enumerator(&self, name: &str, value: i64, is_unsigned: bool) -> DIEnumerator<'ctx> {
+        let metadata_ref = unsafe {
+            LLVMDIBuilderCreateEnumerator(self.builder, name.as_ptr() as _, name.len(), value, is_unsigned as _)
+        };
+        DIEnumerator {
+            metadata_ref,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Create an enumeration type made up of `elements`. The `underlying_type`, if any, is
+    /// the integer type the enumeration is represented with.
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_enumeration_type(
+        &self,
+        scope: DIScope<'ctx>,
+        name: &str,
+        file: DIFile<'ctx>,
+        line_no: u32,
+        size_in_bits: u64,
+        align_in_bits: u32,
+        elements: &[DIEnumerator<'ctx>],
+        underlying_type: Option<DIType<'ctx>>,
+    ) -> DICompositeType<'ctx> {
+        let mut elements: Vec<LLVMMetadataRef> = elements.iter().map(|de| de.metadata_ref).collect();
+        let underlying_type = underlying_type.map_or(std::ptr::null_mut(), |dt| dt.metadata_ref);
+        let metadata_ref = unsafe {
+            LLVMDIBuilderCreateEnumerationType(
+                self.builder,
+                scope.metadata_ref,
+                name.as_ptr() as _,
+                name.len(),
+                file.metadata_ref,
+                line_no,
+                size_in_bits,
+                align_in_bits,
+                elements.as_mut_ptr(),
+                elements.len().try_into().unwrap(),
+                underlying_type,
+            )
+        };
+        DICompositeType {
+            metadata_ref,
+            _marker: PhantomData,
+        }
+    }
+
     /// Create a type for a non-static member.
     pub fn create_member_type(
         &self,
@@ -1180,6 +1230,13 @@ impl<'ctx> AsDIScope<'ctx> for DICompositeType<'ctx> {
     }
 }
 
+/// A named value of an enumeration type, created by `create_enumerator` of `DebugInfoBuilder`
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct DIEnumerator<'ctx> {
+    pub(crate) metadata_ref: LLVMMetadataRef,
+    _marker: PhantomData<&'ctx Context>,
+}
+
 /// Metadata representing the type of a function
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub struct DISubroutineType<'ctx> {
@@ -1232,6 +1289,24 @@ impl<'ctx> DILocation<'ctx> {
             _marker: PhantomData,
         }
     }
+
+    /// Gets the location this location was inlined at, if any. Following the chain of
+    /// `inlined_at` locations yields the frames a backtrace would show for inlined code.
+    #[llvm_versions(9.0..=latest)]
+    pub fn get_inlined_at(&self) -> Option<DILocation<'ctx>> {
+        use llvm_sys::debuginfo::LLVMDILocationGetInlinedAt;
+
+        let metadata_ref = unsafe { LLVMDILocationGetInlinedAt(self.metadata_ref) };
+
+        if metadata_ref.is_null() {
+            return None;
+        }
+
+        Some(DILocation {
+            metadata_ref,
+            _marker: PhantomData,
+        })
+    }
 }
 
 /// Metadata representing a variable inside a scope
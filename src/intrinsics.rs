@@ -1,6 +1,6 @@
 #[llvm_versions(9.0..=latest)]
 use llvm_sys::core::{LLVMGetIntrinsicDeclaration, LLVMIntrinsicIsOverloaded, LLVMLookupIntrinsicID};
-use llvm_sys::prelude::LLVMTypeRef;
+use llvm_sys::prelude::{LLVMModuleRef, LLVMTypeRef};
 
 use crate::module::Module;
 use crate::types::{AsTypeRef, BasicTypeEnum};
@@ -88,6 +88,18 @@ impl Intrinsic {
         &self,
         module: &Module<'ctx>,
         param_types: &[BasicTypeEnum],
+    ) -> Option<FunctionValue<'ctx>> {
+        unsafe { self.get_declaration_in(module.module.get(), param_types) }
+    }
+
+    /// Same as [`Self::get_declaration()`], but takes a raw module reference so that
+    /// the `Builder` can declare intrinsics in the module it is currently positioned in.
+    ///
+    /// SAFETY: the module reference must be valid
+    pub(crate) unsafe fn get_declaration_in<'ctx>(
+        &self,
+        module: LLVMModuleRef,
+        param_types: &[BasicTypeEnum],
     ) -> Option<FunctionValue<'ctx>> {
         let mut param_types: Vec<LLVMTypeRef> = param_types.iter().map(|val| val.as_type_ref()).collect();
 
@@ -99,15 +111,11 @@ impl Intrinsic {
             return None;
         }
 
-        let res = unsafe {
-            FunctionValue::new(LLVMGetIntrinsicDeclaration(
-                module.module.get(),
-                self.id,
-                param_types.as_mut_ptr(),
-                param_types.len(),
-            ))
-        };
-
-        res
+        FunctionValue::new(LLVMGetIntrinsicDeclaration(
+            module,
+            self.id,
+            param_types.as_mut_ptr(),
+            param_types.len(),
+        ))
     }
 }
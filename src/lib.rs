@@ -36,7 +36,11 @@ pub mod memory_buffer;
 pub mod module;
 pub mod object_file;
 pub mod passes;
+#[cfg(feature = "llvm14-0")]
+mod target_features;
 pub mod targets;
+#[deny(missing_docs)]
+pub mod testing;
 pub mod types;
 pub mod values;
 
@@ -107,17 +111,58 @@ assert_unique_used_features! {"llvm4-0", "llvm5-0", "llvm6-0", "llvm7-0", "llvm8
 
 /// Defines the address space in which a global will be inserted.
 ///
+/// The meaning of an address space depends on the target. Most variants are named after the
+/// address spaces NVPTX and AMDGPU share, and `Region` after the one only AMDGPU has; the
+/// associated constants give the names each of them uses.
+///
+/// More address spaces may be added in the future, so matches on it need a wildcard arm.
+///
 /// # Remarks
 /// See also: https://llvm.org/doxygen/NVPTXBaseInfo_8h_source.html
+/// and https://llvm.org/docs/AMDGPUUsage.html#address-spaces
 #[derive(Debug, PartialEq, Eq, Copy, Clone)]
+#[non_exhaustive]
 pub enum AddressSpace {
+    /// The default address space. Pointers into it may point into any other address space
+    /// on GPU targets.
     Generic = 0,
+    /// Memory visible to all threads of a device.
     Global = 1,
+    /// AMDGPU's global data share, memory shared by all work groups of a device.
+    Region = 2,
+    /// Memory shared by the threads of a block or work group.
     Shared = 3,
+    /// Read only memory visible to all threads of a device.
     Const = 4,
+    /// Memory private to a single thread.
     Local = 5,
 }
 
+impl AddressSpace {
+    /// AMDGPU's flat address space, which covers all of the other ones.
+    pub const AMDGPU_FLAT: AddressSpace = AddressSpace::Generic;
+    /// AMDGPU's global memory.
+    pub const AMDGPU_GLOBAL: AddressSpace = AddressSpace::Global;
+    /// AMDGPU's global data share.
+    pub const AMDGPU_REGION: AddressSpace = AddressSpace::Region;
+    /// AMDGPU's local data share, memory shared by a work group.
+    pub const AMDGPU_LOCAL: AddressSpace = AddressSpace::Shared;
+    /// AMDGPU's constant memory.
+    pub const AMDGPU_CONSTANT: AddressSpace = AddressSpace::Const;
+    /// AMDGPU's private memory, where allocas live.
+    pub const AMDGPU_PRIVATE: AddressSpace = AddressSpace::Local;
+    /// NVPTX's generic address space.
+    pub const NVPTX_GENERIC: AddressSpace = AddressSpace::Generic;
+    /// NVPTX's global memory.
+    pub const NVPTX_GLOBAL: AddressSpace = AddressSpace::Global;
+    /// NVPTX's shared memory, shared by the threads of a block.
+    pub const NVPTX_SHARED: AddressSpace = AddressSpace::Shared;
+    /// NVPTX's constant memory.
+    pub const NVPTX_CONSTANT: AddressSpace = AddressSpace::Const;
+    /// NVPTX's local memory, private to a thread.
+    pub const NVPTX_LOCAL: AddressSpace = AddressSpace::Local;
+}
+
 impl TryFrom<u32> for AddressSpace {
     type Error = ();
 
@@ -125,6 +170,7 @@ impl TryFrom<u32> for AddressSpace {
         match val {
             0 => Ok(AddressSpace::Generic),
             1 => Ok(AddressSpace::Global),
+            2 => Ok(AddressSpace::Region),
             3 => Ok(AddressSpace::Shared),
             4 => Ok(AddressSpace::Const),
             5 => Ok(AddressSpace::Local),
@@ -2,18 +2,20 @@
 
 use llvm_sys::analysis::{LLVMVerifierFailureAction, LLVMVerifyModule};
 #[allow(deprecated)]
-use llvm_sys::bit_reader::LLVMParseBitcodeInContext;
+use llvm_sys::bit_reader::{LLVMGetBitcodeModuleInContext, LLVMParseBitcodeInContext};
 use llvm_sys::bit_writer::{LLVMWriteBitcodeToFile, LLVMWriteBitcodeToMemoryBuffer};
 #[llvm_versions(4.0..14.0)]
 use llvm_sys::core::LLVMGetTypeByName;
 
-
 use llvm_sys::core::{
     LLVMAddFunction, LLVMAddGlobal, LLVMAddGlobalInAddressSpace, LLVMAddNamedMetadataOperand, LLVMCloneModule,
-    LLVMDisposeModule, LLVMDumpModule, LLVMGetFirstFunction, LLVMGetFirstGlobal, LLVMGetLastFunction,
-    LLVMGetLastGlobal, LLVMGetModuleContext, LLVMGetModuleIdentifier, LLVMGetNamedFunction, LLVMGetNamedGlobal,
-    LLVMGetNamedMetadataNumOperands, LLVMGetNamedMetadataOperands, LLVMGetTarget, LLVMPrintModuleToFile,
-    LLVMPrintModuleToString, LLVMSetDataLayout, LLVMSetModuleIdentifier, LLVMSetTarget,
+    LLVMConstArray, LLVMConstNull, LLVMDeleteFunction, LLVMDisposeModule, LLVMDumpModule, LLVMGetElementAsConstant,
+    LLVMGetFirstFunction, LLVMGetFirstGlobal, LLVMGetGlobalParent, LLVMGetLastFunction, LLVMGetLastGlobal,
+    LLVMGetModuleContext, LLVMGetModuleIdentifier, LLVMGetNamedFunction, LLVMGetNamedGlobal,
+    LLVMGetNamedMetadataNumOperands, LLVMGetNamedMetadataOperands, LLVMGetOperand, LLVMGetTarget,
+    LLVMIsAConstantAggregateZero, LLVMIsAConstantDataArray, LLVMIsAFunction, LLVMIsConstant, LLVMPrintModuleToFile,
+    LLVMPrintModuleToString, LLVMReplaceAllUsesWith, LLVMSetDataLayout, LLVMSetInitializer, LLVMSetModuleIdentifier,
+    LLVMSetTarget,
 };
 #[llvm_versions(7.0..=latest)]
 use llvm_sys::core::{LLVMAddModuleFlag, LLVMGetModuleFlag};
@@ -38,6 +40,7 @@ use std::path::Path;
 use std::ptr;
 use std::rc::Rc;
 
+use crate::attributes::AttributeLoc;
 #[llvm_versions(7.0..=latest)]
 use crate::comdat::Comdat;
 use crate::context::{AsContextRef, Context, ContextRef};
@@ -48,14 +51,13 @@ use crate::execution_engine::ExecutionEngine;
 use crate::memory_buffer::MemoryBuffer;
 #[llvm_versions(13.0..=latest)]
 use crate::passes::PassBuilderOptions;
+use crate::passes::PassManager;
 use crate::support::{to_c_str, LLVMString};
 #[llvm_versions(13.0..=latest)]
 use crate::targets::TargetMachine;
 use crate::targets::{InitializationConfig, Target, TargetTriple};
 use crate::types::{AsTypeRef, BasicType, FunctionType, StructType};
-#[llvm_versions(7.0..=latest)]
-use crate::values::BasicValue;
-use crate::values::{AsValueRef, FunctionValue, GlobalValue, MetadataValue};
+use crate::values::{ArrayValue, AsValueRef, BasicValue, BasicValueEnum, FunctionValue, GlobalValue, MetadataValue};
 #[cfg(feature = "internal-getters")]
 use crate::LLVMReference;
 use crate::{AddressSpace, OptimizationLevel};
@@ -641,6 +643,253 @@ impl<'ctx> Module<'ctx> {
         unsafe { GlobalValue::new(value) }
     }
 
+    /// Registers `function` to be run before `main` by appending it to the `llvm.global_ctors` array,
+    /// which is created if it does not exist yet. Constructors with a lower `priority` run first;
+    /// `65535` is the default priority used by C and C++ compilers.
+    ///
+    /// If `associated_data` is provided, the constructor is discarded along with it when that global
+    /// is discarded (ie because its comdat was not chosen by the linker).
+    ///
+    /// `function` must take no parameters and return void.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_module");
+    /// let builder = context.create_builder();
+    /// let fn_type = context.void_type().fn_type(&[], false);
+    /// let init = module.add_function("init_runtime", fn_type, None);
+    /// let entry = context.append_basic_block(init, "entry");
+    ///
+    /// builder.position_at_end(entry);
+    /// builder.build_return(None);
+    ///
+    /// module.add_global_constructor(65535, init, None).unwrap();
+    ///
+    /// assert!(module.get_global("llvm.global_ctors").is_some());
+    /// ```
+    pub fn add_global_constructor(
+        &self,
+        priority: u32,
+        function: FunctionValue<'ctx>,
+        associated_data: Option<GlobalValue<'ctx>>,
+    ) -> Result<(), &'static str> {
+        self.add_global_structor("llvm.global_ctors", priority, function, associated_data)
+    }
+
+    /// Registers `function` to be run after `main` returns (or on `exit`) by appending it to the
+    /// `llvm.global_dtors` array, which is created if it does not exist yet. Destructors with a lower
+    /// `priority` run first.
+    ///
+    /// See [`Module::add_global_constructor`] for the meaning of the other arguments.
+    pub fn add_global_destructor(
+        &self,
+        priority: u32,
+        function: FunctionValue<'ctx>,
+        associated_data: Option<GlobalValue<'ctx>>,
+    ) -> Result<(), &'static str> {
+        self.add_global_structor("llvm.global_dtors", priority, function, associated_data)
+    }
+
+    // Each entry is a `{ i32 priority, void ()* function, i8* associated_data }` struct
+    fn add_global_structor(
+        &self,
+        array_name: &str,
+        priority: u32,
+        function: FunctionValue<'ctx>,
+        associated_data: Option<GlobalValue<'ctx>>,
+    ) -> Result<(), &'static str> {
+        let fn_type = function.get_type();
+
+        if fn_type.get_return_type().is_some() || fn_type.count_param_types() != 0 {
+            return Err("Global constructors and destructors must take no parameters and return void.");
+        }
+
+        let context = self.get_context();
+        let i8_ptr_type = context.i8_type().ptr_type(AddressSpace::Generic);
+        let fn_ptr = function.as_global_value().as_pointer_value();
+        let entry_type = context.struct_type(
+            &[context.i32_type().into(), fn_ptr.get_type().into(), i8_ptr_type.into()],
+            false,
+        );
+        let data = match associated_data {
+            Some(global) => global.as_pointer_value().const_cast(i8_ptr_type),
+            None => i8_ptr_type.const_null(),
+        };
+        let entry = entry_type.const_named_struct(&[
+            context.i32_type().const_int(priority as u64, false).into(),
+            fn_ptr.into(),
+            data.into(),
+        ]);
+
+        self.append_to_global_array(array_name, entry_type, &[entry.into()])
+            .map(|_| ())
+    }
+
+    /// Appends `globals` to the `llvm.used` array, which is created if it does not exist yet. Globals in
+    /// this array are treated as if they had a reference the compiler and linker cannot see, so they
+    /// survive global dead code elimination and LTO even if they are only referenced from inline
+    /// assembly or by external tools. Globals which are already in the array are skipped.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    /// use inkwell::module::Linkage;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_module");
+    /// let i32_type = context.i32_type();
+    /// let global = module.add_global(i32_type, None, "referenced_from_asm");
+    ///
+    /// global.set_initializer(&i32_type.const_zero());
+    /// global.set_linkage(Linkage::Internal);
+    ///
+    /// module.append_to_used(&[global]).unwrap();
+    ///
+    /// assert!(module.get_global("llvm.used").is_some());
+    /// ```
+    pub fn append_to_used(&self, globals: &[GlobalValue<'ctx>]) -> Result<(), &'static str> {
+        self.append_to_used_array("llvm.used", globals)
+    }
+
+    /// Appends `globals` to the `llvm.compiler.used` array, which is created if it does not exist yet.
+    /// Unlike [`Module::append_to_used`], this only keeps the compiler from removing the globals; the
+    /// linker may still discard them if they are unreferenced.
+    pub fn append_to_compiler_used(&self, globals: &[GlobalValue<'ctx>]) -> Result<(), &'static str> {
+        self.append_to_used_array("llvm.compiler.used", globals)
+    }
+
+    fn append_to_used_array(&self, array_name: &str, globals: &[GlobalValue<'ctx>]) -> Result<(), &'static str> {
+        let i8_ptr_type = self.get_context().i8_type().ptr_type(AddressSpace::Generic);
+        let mut present = match self.get_global(array_name).and_then(|global| global.get_initializer()) {
+            Some(initializer) if initializer.is_array_value() => const_array_elements(initializer.into_array_value()),
+            _ => Vec::new(),
+        };
+        let mut elements = Vec::new();
+
+        for global in globals {
+            let ptr = global.as_pointer_value();
+            let element = if ptr.get_type().get_address_space() == AddressSpace::Generic {
+                ptr.const_cast(i8_ptr_type)
+            } else {
+                ptr.const_address_space_cast(i8_ptr_type)
+            };
+
+            // Constant expressions are uniqued, so casts of the same global compare equal
+            if present.contains(&element.as_value_ref()) {
+                continue;
+            }
+
+            present.push(element.as_value_ref());
+            elements.push(element.into());
+        }
+
+        let array = self.append_to_global_array(array_name, i8_ptr_type, &elements)?;
+
+        array.set_section(Some("llvm.metadata"));
+
+        Ok(())
+    }
+
+    /// Appends the constant `elements` to the initializer of the appending linkage global array `name`,
+    /// creating the global if it does not exist, and returns the resulting global. When modules are
+    /// linked together, appending arrays with the same name are concatenated, which makes them a good
+    /// fit for per module registration lists or coverage tables.
+    ///
+    /// Since the length of an array is part of its type, the existing global is replaced by a new one
+    /// with the same name and section, so it must not be used by any other value.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    /// use inkwell::module::Linkage;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_module");
+    /// let i32_type = context.i32_type();
+    ///
+    /// module.append_to_global_array("my_registry", i32_type, &[i32_type.const_int(1, false).into()]).unwrap();
+    ///
+    /// let registry = module.append_to_global_array("my_registry", i32_type, &[i32_type.const_int(2, false).into()]).unwrap();
+    ///
+    /// assert_eq!(registry.get_linkage(), Linkage::Appending);
+    /// assert_eq!(registry.get_initializer().unwrap().into_array_value().get_type().len(), 2);
+    /// ```
+    pub fn append_to_global_array<T: BasicType<'ctx>>(
+        &self,
+        name: &str,
+        element_type: T,
+        elements: &[BasicValueEnum<'ctx>],
+    ) -> Result<GlobalValue<'ctx>, &'static str> {
+        let element_type = element_type.as_basic_type_enum();
+
+        if elements.iter().any(|element| element.get_type() != element_type) {
+            return Err("All elements must be of the array's element type.");
+        }
+
+        if elements
+            .iter()
+            .any(|element| unsafe { LLVMIsConstant(element.as_value_ref()) } == 0)
+        {
+            return Err("All elements must be constants.");
+        }
+
+        let mut values = Vec::new();
+        let mut section = None;
+
+        if let Some(existing) = self.get_global(name) {
+            if existing.get_linkage() != Linkage::Appending {
+                return Err("The existing global does not have appending linkage.");
+            }
+
+            if let Some(initializer) = existing.get_initializer() {
+                if !initializer.is_array_value() {
+                    return Err("The existing global is not an array.");
+                }
+
+                let array = initializer.into_array_value();
+
+                if array.get_type().get_element_type() != element_type {
+                    return Err("The existing array has a different element type.");
+                }
+
+                values.extend(const_array_elements(array));
+            }
+
+            if existing.as_pointer_value().get_first_use().is_some() {
+                return Err("The existing global array must not be used by other values.");
+            }
+
+            section = existing
+                .get_section()
+                .map(|section| section.to_string_lossy().into_owned());
+
+            unsafe { existing.delete() };
+        }
+
+        values.extend(elements.iter().map(|element| element.as_value_ref()));
+
+        let array = unsafe {
+            ArrayValue::new(LLVMConstArray(
+                element_type.as_type_ref(),
+                values.as_mut_ptr(),
+                values.len() as u32,
+            ))
+        };
+        let global = self.add_global(array.get_type(), None, name);
+
+        global.set_initializer(&array);
+        global.set_linkage(Linkage::Appending);
+        global.set_section(section.as_deref());
+
+        Ok(global)
+    }
+
     /// Writes a `Module` to a `Path`.
     ///
     /// # Example
@@ -716,6 +965,79 @@ impl<'ctx> Module<'ctx> {
         MemoryBuffer::new(memory_buffer)
     }
 
+    /// Compiles this `Module` into a WebAssembly object file in memory, registering the
+    /// WebAssembly target first. The module's target triple is used if it is a `wasm32` or
+    /// `wasm64` triple, and `wasm32-unknown-unknown` if it has none. With LLVM 14, `features`
+    /// are first checked with `TargetFeatures::validate`.
+    ///
+    /// Functions can be imported and exported under different names with
+    /// `FunctionValue::set_wasm_import` and `FunctionValue::set_wasm_export_name`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    /// use inkwell::targets::TargetFeatures;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("mod");
+    /// let builder = context.create_builder();
+    /// let fn_type = context.void_type().fn_type(&[], false);
+    /// let log = module.add_function("log", fn_type, None);
+    /// let run = module.add_function("run", fn_type, None);
+    ///
+    /// log.set_wasm_import("console", "log");
+    /// run.set_wasm_export_name("run");
+    ///
+    /// builder.position_at_end(context.append_basic_block(run, "entry"));
+    /// builder.build_call(log, &[], "");
+    /// builder.build_return(None);
+    ///
+    /// let features = TargetFeatures::new().enable("simd128");
+    /// let buffer = module.write_wasm_object_to_memory_buffer(&features).unwrap();
+    ///
+    /// assert!(buffer.as_slice().starts_with(b"\0asm"));
+    /// ```
+    #[cfg(feature = "target-webassembly")]
+    pub fn write_wasm_object_to_memory_buffer(
+        &self,
+        features: &crate::targets::TargetFeatures,
+    ) -> Result<MemoryBuffer, LLVMString> {
+        use crate::targets::{CodeModel, FileType, RelocMode, TargetFeaturesError};
+
+        Target::initialize_webassembly(&InitializationConfig::default());
+
+        let triple = self.get_triple();
+        let triple = match triple.as_str().to_bytes() {
+            b"" => TargetTriple::create("wasm32-unknown-unknown"),
+            bytes if bytes.starts_with(b"wasm32") || bytes.starts_with(b"wasm64") => triple,
+            _ => {
+                let string = "The module's target triple is not a WebAssembly triple.\0";
+
+                return Err(LLVMString::create_from_str(string));
+            },
+        };
+        let target = Target::from_triple(&triple)?;
+
+        match features.validate(&target) {
+            Ok(()) | Err(TargetFeaturesError::CannotValidate) => {},
+            Err(err) => return Err(LLVMString::create_from_str(&format!("{}\0", err))),
+        }
+
+        let target_machine = target
+            .create_target_machine(
+                &triple,
+                "generic",
+                &features.to_string(),
+                OptimizationLevel::Default,
+                RelocMode::Default,
+                CodeModel::Default,
+            )
+            .ok_or_else(|| LLVMString::create_from_str("Could not create a WebAssembly target machine.\0"))?;
+
+        target_machine.write_to_memory_buffer(self, FileType::Object)
+    }
+
     /// Ensures that the current `Module` is valid, and returns a `Result`
     /// that describes whether or not it is, returning a LLVM allocated string on error.
     ///
@@ -1098,6 +1420,72 @@ impl<'ctx> Module<'ctx> {
         unsafe { Some(GlobalValue::new(value)) }
     }
 
+    /// Adds an indirect function named `name` to this module. The first call through it has
+    /// the dynamic loader run `resolver`, which returns the address of the implementation to
+    /// use, such as one picked based on the CPU features available at runtime. `ty` is the
+    /// type of the implementations.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    /// use inkwell::AddressSpace;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("mod");
+    /// let builder = context.create_builder();
+    /// let fn_type = context.i32_type().fn_type(&[], false);
+    /// let resolver_type = fn_type.ptr_type(AddressSpace::Generic).fn_type(&[], false);
+    /// let implementation = module.add_function("popcount_generic", fn_type, None);
+    /// let resolver = module.add_function("popcount_resolver", resolver_type, None);
+    ///
+    /// builder.position_at_end(context.append_basic_block(resolver, "entry"));
+    /// builder.build_return(Some(&implementation.as_global_value().as_pointer_value()));
+    ///
+    /// let ifunc = module.add_global_ifunc("popcount", fn_type, AddressSpace::Generic, resolver);
+    ///
+    /// assert_eq!(module.get_global_ifunc("popcount"), Some(ifunc));
+    /// assert_eq!(ifunc.get_ifunc_resolver(), Some(resolver));
+    /// ```
+    #[llvm_versions(9.0..=latest)]
+    pub fn add_global_ifunc(
+        &self,
+        name: &str,
+        ty: FunctionType<'ctx>,
+        address_space: AddressSpace,
+        resolver: FunctionValue<'ctx>,
+    ) -> GlobalValue<'ctx> {
+        use llvm_sys::core::LLVMAddGlobalIFunc;
+
+        let value = unsafe {
+            LLVMAddGlobalIFunc(
+                self.module.get(),
+                name.as_ptr() as *const ::libc::c_char,
+                name.len(),
+                ty.as_type_ref(),
+                address_space as u32,
+                resolver.as_value_ref(),
+            )
+        };
+
+        unsafe { GlobalValue::new(value) }
+    }
+
+    /// Gets a named indirect function in a module.
+    #[llvm_versions(9.0..=latest)]
+    pub fn get_global_ifunc(&self, name: &str) -> Option<GlobalValue<'ctx>> {
+        use llvm_sys::core::LLVMGetNamedGlobalIFunc;
+
+        let value =
+            unsafe { LLVMGetNamedGlobalIFunc(self.module.get(), name.as_ptr() as *const ::libc::c_char, name.len()) };
+
+        if value.is_null() {
+            return None;
+        }
+
+        unsafe { Some(GlobalValue::new(value)) }
+    }
+
     /// Creates a new `Module` from a `MemoryBuffer`.
     ///
     /// # Example
@@ -1145,6 +1533,217 @@ impl<'ctx> Module<'ctx> {
         unsafe { Ok(Module::new(module.assume_init())) }
     }
 
+    /// Creates a new `Module` from a `MemoryBuffer` without loading any function bodies.
+    /// Function bodies are only read from the bitcode once they are needed, such as when the
+    /// function is linked into another module by `link_in_module`, or explicitly through
+    /// `materialize_function`. This keeps linking a large precompiled library cheap when
+    /// only a few of its functions are used.
+    ///
+    /// The returned `Module` takes ownership of the `MemoryBuffer`, as the bitcode
+    /// has to stay around until everything has been materialized.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    /// use inkwell::module::Module;
+    /// use inkwell::memory_buffer::MemoryBuffer;
+    /// use std::path::Path;
+    ///
+    /// let path = Path::new("foo/bar.bc");
+    /// let context = Context::create();
+    /// let buffer = MemoryBuffer::create_from_file(&path).unwrap();
+    /// let module = Module::parse_bitcode_from_buffer_lazily(buffer, &context).unwrap();
+    /// let function = module.get_function("foo").unwrap();
+    ///
+    /// assert!(function.is_materializable());
+    ///
+    /// module.materialize_function(function).unwrap();
+    ///
+    /// assert!(!function.is_materializable());
+    /// ```
+    pub fn parse_bitcode_from_buffer_lazily(
+        buffer: MemoryBuffer,
+        context: impl AsContextRef<'ctx>,
+    ) -> Result<Self, LLVMString> {
+        let mut module = MaybeUninit::uninit();
+        let mut err_string = MaybeUninit::uninit();
+
+        // See parse_bitcode_from_buffer regarding the deprecated variant
+        #[allow(deprecated)]
+        let success = unsafe {
+            LLVMGetBitcodeModuleInContext(
+                context.as_ctx_ref(),
+                buffer.memory_buffer,
+                module.as_mut_ptr(),
+                err_string.as_mut_ptr(),
+            )
+        };
+
+        // The buffer only belongs to the module on success
+        if success != 0 {
+            unsafe {
+                return Err(LLVMString::new(err_string.assume_init()));
+            }
+        }
+
+        forget(buffer);
+
+        unsafe { Ok(Module::new(module.assume_init())) }
+    }
+
+    /// Loads the body of a function in this `Module` which was created by
+    /// `parse_bitcode_from_buffer_lazily`. Functions which are already materialized
+    /// are left untouched.
+    ///
+    /// LLVM aborts the process if the bitcode for the function turns out to be invalid.
+    pub fn materialize_function(&self, function: FunctionValue<'ctx>) -> Result<(), &'static str> {
+        if unsafe { LLVMGetGlobalParent(function.as_value_ref()) } != self.module.get() {
+            return Err("The function does not belong to this module.");
+        }
+
+        if !function.is_materializable() {
+            return Ok(());
+        }
+
+        // The C API has no direct way to materialize a function, but running a function
+        // pass manager on it materializes it first.
+        let fpm = PassManager::create(self);
+
+        fpm.initialize();
+        fpm.run_on(&function);
+        fpm.finalize();
+
+        Ok(())
+    }
+
+    /// Marks `function` as an NVPTX kernel entry point by adding it to the module's
+    /// `nvvm.annotations` metadata, optionally along with its launch bounds as `maxntidx`
+    /// and `minctasm` annotations.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    /// use inkwell::module::LaunchBounds;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("kernels");
+    /// let fn_type = context.void_type().fn_type(&[], false);
+    /// let kernel = module.add_function("kernel", fn_type, None);
+    /// let launch_bounds = LaunchBounds {
+    ///     max_threads_per_block: 256,
+    ///     min_blocks_per_multiprocessor: Some(2),
+    /// };
+    ///
+    /// module.add_nvptx_kernel(kernel, Some(launch_bounds)).unwrap();
+    ///
+    /// assert_eq!(module.get_global_metadata_size("nvvm.annotations"), 3);
+    /// ```
+    pub fn add_nvptx_kernel(
+        &self,
+        function: FunctionValue<'ctx>,
+        launch_bounds: Option<LaunchBounds>,
+    ) -> Result<(), &'static str> {
+        self.check_kernel(function)?;
+
+        let context = self.get_context();
+        let i32_type = context.i32_type();
+        let function_ptr = function.as_global_value().as_pointer_value();
+        let mut annotations = vec![("kernel", 1)];
+
+        if let Some(launch_bounds) = launch_bounds {
+            annotations.push(("maxntidx", launch_bounds.max_threads_per_block));
+
+            if let Some(min_blocks) = launch_bounds.min_blocks_per_multiprocessor {
+                annotations.push(("minctasm", min_blocks));
+            }
+        }
+
+        for (key, value) in annotations {
+            let node = context.metadata_node(&[
+                function_ptr.into(),
+                context.metadata_string(key).into(),
+                i32_type.const_int(value as u64, false).into(),
+            ]);
+
+            self.add_global_metadata("nvvm.annotations", &node)?;
+        }
+
+        Ok(())
+    }
+
+    /// Marks `function` as an AMDGPU kernel entry point by giving it the `amdgpu_kernel`
+    /// calling convention. Launch bounds are set through the `amdgpu-flat-work-group-size`
+    /// attribute and, following HIP's `__launch_bounds__`, the minimum is passed on as the
+    /// `amdgpu-waves-per-eu` attribute.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::attributes::AttributeLoc;
+    /// use inkwell::context::Context;
+    /// use inkwell::module::LaunchBounds;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("kernels");
+    /// let fn_type = context.void_type().fn_type(&[], false);
+    /// let kernel = module.add_function("kernel", fn_type, None);
+    /// let launch_bounds = LaunchBounds {
+    ///     max_threads_per_block: 256,
+    ///     min_blocks_per_multiprocessor: None,
+    /// };
+    ///
+    /// module.add_amdgpu_kernel(kernel, Some(launch_bounds)).unwrap();
+    ///
+    /// let work_group_size = kernel.get_string_attribute(AttributeLoc::Function, "amdgpu-flat-work-group-size");
+    ///
+    /// assert_eq!(work_group_size.unwrap().get_string_value().to_str(), Ok("1,256"));
+    /// ```
+    pub fn add_amdgpu_kernel(
+        &self,
+        function: FunctionValue<'ctx>,
+        launch_bounds: Option<LaunchBounds>,
+    ) -> Result<(), &'static str> {
+        // CallingConv::AMDGPU_KERNEL
+        const AMDGPU_KERNEL_CALL_CONV: u32 = 91;
+
+        self.check_kernel(function)?;
+
+        function.set_call_conventions(AMDGPU_KERNEL_CALL_CONV);
+
+        if let Some(launch_bounds) = launch_bounds {
+            let context = self.get_context();
+            let work_group_size = format!("1,{}", launch_bounds.max_threads_per_block);
+
+            function.add_attribute(
+                AttributeLoc::Function,
+                context.create_string_attribute("amdgpu-flat-work-group-size", &work_group_size),
+            );
+
+            if let Some(min_blocks) = launch_bounds.min_blocks_per_multiprocessor {
+                function.add_attribute(
+                    AttributeLoc::Function,
+                    context.create_string_attribute("amdgpu-waves-per-eu", &min_blocks.to_string()),
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    fn check_kernel(&self, function: FunctionValue<'ctx>) -> Result<(), &'static str> {
+        if unsafe { LLVMGetGlobalParent(function.as_value_ref()) } != self.module.get() {
+            return Err("The function does not belong to this module.");
+        }
+
+        if function.get_type().get_return_type().is_some() {
+            return Err("GPU kernels must return void.");
+        }
+
+        Ok(())
+    }
+
     /// A convenience function for creating a `Module` from a file for a given context.
     ///
     /// # Example
@@ -1311,6 +1910,109 @@ impl<'ctx> Module<'ctx> {
         }
     }
 
+    /// Links one module into another like `link_in_module`, but first looks for symbols which
+    /// are strongly defined in both `Module`s. `on_duplicate` is called with the name of every
+    /// such symbol, and `duplicates` decides how the conflicts are resolved. Unlike LLVM, which
+    /// only reports the first conflict it runs into, this reports all of them.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    /// use inkwell::module::DuplicateDefinitions;
+    ///
+    /// let context = Context::create();
+    /// let builder = context.create_builder();
+    /// let fn_type = context.void_type().fn_type(&[], false);
+    /// let module = context.create_module("a.ll");
+    /// let module2 = context.create_module("b.ll");
+    ///
+    /// for module in &[&module, &module2] {
+    ///     let function = module.add_function("foo", fn_type, None);
+    ///
+    ///     builder.position_at_end(context.append_basic_block(function, "entry"));
+    ///     builder.build_return(None);
+    /// }
+    ///
+    /// let mut conflicts = Vec::new();
+    /// let result = module.link_in_module_with_duplicates(module2, DuplicateDefinitions::Error, |name| {
+    ///     conflicts.push(format!("duplicate definition of {} in a.ll and b.ll", name.to_string_lossy()))
+    /// });
+    ///
+    /// assert!(result.is_err());
+    /// assert_eq!(conflicts, ["duplicate definition of foo in a.ll and b.ll"]);
+    /// ```
+    pub fn link_in_module_with_duplicates<F: FnMut(&CStr)>(
+        &self,
+        other: Self,
+        duplicates: DuplicateDefinitions,
+        mut on_duplicate: F,
+    ) -> Result<(), LLVMString> {
+        if other.owned_by_ee.borrow().is_some() {
+            let string = "Cannot link a module which is already owned by an ExecutionEngine.\0";
+            return Err(LLVMString::create_from_str(string));
+        }
+
+        let functions = other.get_functions().map(|function| function.as_global_value());
+        let globals = std::iter::successors(other.get_first_global(), |global| global.get_next_global());
+        let mut conflicts = Vec::new();
+
+        for source in functions.chain(globals) {
+            if !is_conflicting_definition(source) {
+                continue;
+            }
+
+            let name = source.get_name();
+            let destination = unsafe {
+                let function = LLVMGetNamedFunction(self.module.get(), name.as_ptr());
+
+                if function.is_null() {
+                    LLVMGetNamedGlobal(self.module.get(), name.as_ptr())
+                } else {
+                    function
+                }
+            };
+
+            if destination.is_null() {
+                continue;
+            }
+
+            let destination = unsafe { GlobalValue::new(destination) };
+
+            if is_conflicting_definition(destination) {
+                on_duplicate(name);
+                conflicts.push((destination, source));
+            }
+        }
+
+        match duplicates {
+            DuplicateDefinitions::Error => {
+                if let Some((_, source)) = conflicts.first() {
+                    let message = format!(
+                        "Linking globals named '{}': symbol multiply defined!\0",
+                        source.get_name().to_string_lossy()
+                    );
+
+                    return Err(LLVMString::create_from_c_str(
+                        CStr::from_bytes_with_nul(message.as_bytes()).unwrap(),
+                    ));
+                }
+            },
+            DuplicateDefinitions::OverrideFromSource => {
+                for (destination, _) in conflicts {
+                    make_declaration(destination);
+                }
+            },
+            DuplicateDefinitions::KeepDestination => {
+                for (_, source) in conflicts {
+                    make_declaration(source);
+                }
+            },
+        }
+
+        self.link_in_module(other)
+    }
+
     /// Gets the `Comdat` associated with a particular name. If it does not exist, it will be created.
     /// A new `Comdat` defaults to a kind of `ComdatSelectionKind::Any`.
     #[llvm_versions(7.0..=latest)]
@@ -1395,7 +2097,9 @@ impl<'ctx> Module<'ctx> {
         unsafe { LLVMGetModuleDebugMetadataVersion(self.module.get()) }
     }
 
-    /// Creates a `DebugInfoBuilder` for this `Module`.
+    /// Creates a `DebugInfoBuilder` for this `Module`, along with the compile unit it owns. Each
+    /// builder has a single compile unit, so a `Module` holding several source files needs one
+    /// builder per file.
     #[llvm_versions(7.0..=latest)]
     pub fn create_debug_info_builder(
         &self,
@@ -1465,6 +2169,12 @@ impl<'ctx> Module<'ctx> {
     /// Individual passes may be specified, separated by commas.
     /// Full pipelines may also be invoked using default<O3> and friends.
     /// See opt for full reference of the Passes format.
+    ///
+    /// Instrumentation passes are available this way as well. For example, `insert-gcov-profiling`
+    /// adds gcov style coverage counters based on the module's debug info, and `instrprof` lowers
+    /// calls built by `Builder::build_instrprof_increment` into profile counters. Sanitizer
+    /// instrumentation is enabled through `PassBuilderOptions::enable_asan` and friends, and
+    /// rarely executed code is split out with `PassBuilderOptions::set_hot_cold_splitting`.
     #[llvm_versions(13.0..=latest)]
     pub fn run_passes(
         &self,
@@ -1472,16 +2182,24 @@ impl<'ctx> Module<'ctx> {
         machine: &TargetMachine,
         options: PassBuilderOptions,
     ) -> Result<(), LLVMString> {
+        let passes = options.pipeline(self, passes)?;
+        let sanitized = options.add_sanitizer_attributes(self);
+
         unsafe {
             let error = LLVMRunPasses(
                 self.module.get(),
-                to_c_str(passes).as_ptr(),
+                to_c_str(&passes).as_ptr(),
                 machine.target_machine,
                 options.options_ref,
             );
             if error == std::ptr::null_mut() {
+                options.finish(self);
+
                 Ok(())
             } else {
+                // The pipeline failed to parse, so the module is otherwise untouched
+                options.remove_sanitizer_attributes(&sanitized);
+
                 let message = LLVMGetErrorMessage(error);
                 Err(LLVMString::new(message as *const libc::c_char))
             }
@@ -1489,6 +2207,84 @@ impl<'ctx> Module<'ctx> {
     }
 }
 
+// Whether this is a definition which can't be replaced by another one, as weak, linkonce, common,
+// available_externally and appending definitions can
+fn is_strong_definition(global: GlobalValue) -> bool {
+    !global.is_declaration()
+        && !matches!(
+            global.get_linkage(),
+            Linkage::WeakAny
+                | Linkage::WeakODR
+                | Linkage::LinkOnceAny
+                | Linkage::LinkOnceODR
+                | Linkage::LinkOnceODRAutoHide
+                | Linkage::LinkerPrivateWeak
+                | Linkage::ExternalWeak
+                | Linkage::Common
+                | Linkage::AvailableExternally
+                | Linkage::Appending
+        )
+}
+
+// Whether linking another definition of this symbol would be a conflict. Local symbols never
+// conflict, as the linker renames them instead
+fn is_conflicting_definition(global: GlobalValue) -> bool {
+    is_strong_definition(global) && !matches!(global.get_linkage(), Linkage::Internal | Linkage::Private)
+}
+
+// Turns a definition into a declaration, so that linking picks up a definition from elsewhere
+fn make_declaration(global: GlobalValue) {
+    let value = global.as_value_ref();
+
+    if unsafe { LLVMIsAFunction(value) }.is_null() {
+        unsafe {
+            LLVMSetInitializer(value, ptr::null_mut());
+        }
+
+        #[cfg(not(any(feature = "llvm4-0", feature = "llvm5-0", feature = "llvm6-0")))]
+        unsafe {
+            llvm_sys::comdat::LLVMSetComdat(value, ptr::null_mut());
+        }
+
+        global.set_linkage(Linkage::External);
+
+        return;
+    }
+
+    // There is no way to drop just the body of a function, so it gets replaced by a fresh
+    // declaration which takes over its uses and name
+    let function = unsafe { FunctionValue::new(value).unwrap() };
+    let name = global.get_name().to_string_lossy().into_owned();
+
+    unsafe {
+        let module = LLVMGetGlobalParent(value);
+        let declaration = LLVMAddFunction(module, global.get_name().as_ptr(), function.get_type().as_type_ref());
+
+        LLVMReplaceAllUsesWith(value, declaration);
+        LLVMDeleteFunction(value);
+
+        GlobalValue::new(declaration).set_name(&name);
+    }
+}
+
+/// Collects the elements of a constant array, whichever way LLVM chose to represent it.
+fn const_array_elements(array: ArrayValue) -> Vec<LLVMValueRef> {
+    let array_ref = array.as_value_ref();
+    let element_type = array.get_type().get_element_type();
+
+    (0..array.get_type().len())
+        .map(|index| unsafe {
+            if !LLVMIsAConstantAggregateZero(array_ref).is_null() {
+                LLVMConstNull(element_type.as_type_ref())
+            } else if !LLVMIsAConstantDataArray(array_ref).is_null() {
+                LLVMGetElementAsConstant(array_ref, index)
+            } else {
+                LLVMGetOperand(array_ref, index)
+            }
+        })
+        .collect()
+}
+
 impl Clone for Module<'_> {
     fn clone(&self) -> Self {
         // REVIEW: Is this just a LLVM 6 bug? We could conditionally compile this assertion for affected versions
@@ -1559,6 +2355,27 @@ pub enum FlagBehavior {
     AppendUnique,
 }
 
+/// The launch bounds of a GPU kernel, like CUDA's `__launch_bounds__`.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct LaunchBounds {
+    /// The maximum number of threads a block of the kernel is launched with.
+    pub max_threads_per_block: u32,
+    /// The minimum number of blocks to keep resident per multiprocessor, if any.
+    pub min_blocks_per_multiprocessor: Option<u32>,
+}
+
+/// Defines how `Module::link_in_module_with_duplicates` resolves a symbol which is strongly
+/// defined in both modules.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum DuplicateDefinitions {
+    /// Fails linking, as `Module::link_in_module` does.
+    Error,
+    /// Drops the definition of the destination module in favor of the one being linked in.
+    OverrideFromSource,
+    /// Keeps the definition of the destination module and drops the one being linked in.
+    KeepDestination,
+}
+
 /// Iterate over all `FunctionValue`s in an llvm module
 #[derive(Debug)]
 pub struct FunctionIterator<'ctx>(FunctionIteratorInner<'ctx>);
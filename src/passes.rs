@@ -66,7 +66,14 @@ use crate::OptimizationLevel;
 #[cfg(feature = "internal-getters")]
 use crate::LLVMReference;
 
+#[llvm_versions(13.0..=latest)]
+use crate::attributes::{Attribute, AttributeLoc};
+#[llvm_versions(13.0..=latest)]
+use crate::support::LLVMString;
+
 use std::borrow::Borrow;
+#[llvm_versions(13.0..=latest)]
+use std::cell::{Cell, RefCell};
 use std::marker::PhantomData;
 
 // REVIEW: Opt Level might be identical to targets::Option<CodeGenOptLevel>
@@ -1118,10 +1125,49 @@ impl PassRegistry {
     }
 }
 
+/// A sanitizer `PassBuilderOptions` can instrument a module with.
+#[llvm_versions(13.0..=latest)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Sanitizer {
+    /// AddressSanitizer, like clang's `-fsanitize=address`.
+    Address,
+    /// ThreadSanitizer, like clang's `-fsanitize=thread`.
+    Thread,
+    /// MemorySanitizer, like clang's `-fsanitize=memory`.
+    Memory,
+}
+
+#[llvm_versions(13.0..=latest)]
+impl Sanitizer {
+    // The function attribute the instrumentation passes check for
+    fn attribute_name(self) -> &'static str {
+        match self {
+            Sanitizer::Address => "sanitize_address",
+            Sanitizer::Thread => "sanitize_thread",
+            Sanitizer::Memory => "sanitize_memory",
+        }
+    }
+
+    fn passes(self) -> &'static str {
+        match self {
+            #[cfg(feature = "llvm13-0")]
+            Sanitizer::Address => "require<asan-globals-md>,function(asan),asan-module",
+            #[cfg(not(feature = "llvm13-0"))]
+            Sanitizer::Address => "asan-module",
+            Sanitizer::Thread => "tsan-module,function(tsan)",
+            Sanitizer::Memory => "msan-module,function(msan)",
+        }
+    }
+}
+
 #[llvm_versions(13.0..=latest)]
 #[derive(Debug)]
 pub struct PassBuilderOptions {
     pub(crate) options_ref: LLVMPassBuilderOptionsRef,
+    pgo_instr_gen: Cell<bool>,
+    sanitizer: Cell<Option<Sanitizer>>,
+    hot_cold_splitting: Cell<bool>,
+    cold_section: RefCell<Option<String>>,
 }
 
 #[llvm_versions(13.0..=latest)]
@@ -1131,6 +1177,148 @@ impl PassBuilderOptions {
         unsafe {
             PassBuilderOptions {
                 options_ref: LLVMCreatePassBuilderOptions(),
+                pgo_instr_gen: Cell::new(false),
+                sanitizer: Cell::new(None),
+                hot_cold_splitting: Cell::new(false),
+                cold_section: RefCell::new(None),
+            }
+        }
+    }
+
+    /// Toggle instrumenting the module for profile guided optimization before running the
+    /// pipeline, like clang's `-fprofile-generate`. Running the instrumented program writes
+    /// a `.profraw` file which `llvm-profdata merge` turns into a `.profdata` file.
+    pub fn set_pgo_instr_gen(&self, value: bool) {
+        self.pgo_instr_gen.set(value);
+    }
+
+    // REVIEW: Optimizing with a profile, like clang's `-fprofile-use`, needs LLVM's C API to
+    // hand the PassBuilder a profile path. LLVM 14 only takes it through the test only,
+    // process wide `-pgo-test-profile-file` option, so it isn't offered here
+
+    /// Instruments the module with AddressSanitizer after running the pipeline, like
+    /// clang's `-fsanitize=address`. Every function defined in the module is marked
+    /// `sanitize_address` so the instrumentation applies to it.
+    pub fn enable_asan(&self) {
+        self.sanitizer.set(Some(Sanitizer::Address));
+    }
+
+    /// Instruments the module with ThreadSanitizer after running the pipeline, like
+    /// clang's `-fsanitize=thread`. Every function defined in the module is marked
+    /// `sanitize_thread` so the instrumentation applies to it.
+    pub fn enable_tsan(&self) {
+        self.sanitizer.set(Some(Sanitizer::Thread));
+    }
+
+    /// Instruments the module with MemorySanitizer after running the pipeline, like
+    /// clang's `-fsanitize=memory`. Every function defined in the module is marked
+    /// `sanitize_memory` so the instrumentation applies to it.
+    ///
+    /// MemorySanitizer depends on the memory layout of the target, so the module must
+    /// have a Linux, FreeBSD or NetBSD target triple set.
+    pub fn enable_msan(&self) {
+        self.sanitizer.set(Some(Sanitizer::Memory));
+    }
+
+    /// Gets the sanitizer the module will be instrumented with, if any.
+    pub fn get_sanitizer(&self) -> Option<Sanitizer> {
+        self.sanitizer.get()
+    }
+
+    /// Toggle outlining rarely executed code, such as blocks ending in `unreachable` or
+    /// calling `cold` functions, into separate `cold` functions after running the pipeline,
+    /// like clang's `-mllvm -hot-cold-split`. This keeps such code out of the instruction
+    /// cache when the hot path runs.
+    pub fn set_hot_cold_splitting(&self, value: bool) {
+        self.hot_cold_splitting.set(value);
+    }
+
+    /// Sets the section, such as `.text.unlikely`, that functions with the `cold` attribute
+    /// are placed in after running the pipeline. This includes functions outlined by
+    /// `set_hot_cold_splitting`. Functions which already have a section keep it.
+    pub fn set_cold_section(&self, section: Option<&str>) {
+        *self.cold_section.borrow_mut() = section.map(str::to_owned);
+    }
+
+    // Adds the passes the options imply to the requested pipeline and prepares the
+    // module for them
+    pub(crate) fn pipeline(&self, module: &Module, passes: &str) -> Result<String, LLVMString> {
+        let mut pipeline = Vec::new();
+
+        if self.pgo_instr_gen.get() {
+            pipeline.push("pgo-instr-gen,instrprof");
+        }
+
+        if !passes.is_empty() {
+            pipeline.push(passes);
+        }
+
+        if self.hot_cold_splitting.get() {
+            pipeline.push("hotcoldsplit");
+        }
+
+        if let Some(sanitizer) = self.sanitizer.get() {
+            if sanitizer == Sanitizer::Memory {
+                let triple = module.get_triple();
+                let triple = triple.as_str().to_string_lossy();
+
+                // LLVM aborts the process on any other operating system
+                if !["linux", "freebsd", "netbsd"].iter().any(|os| triple.contains(os)) {
+                    let string = "MemorySanitizer requires a Linux, FreeBSD or NetBSD target triple.\0";
+                    return Err(LLVMString::create_from_str(string));
+                }
+            }
+
+            pipeline.push(sanitizer.passes());
+        }
+
+        Ok(pipeline.join(","))
+    }
+
+    // Marks the functions defined in the module with the sanitizer's attribute, which its passes
+    // only instrument functions with, and returns the functions which didn't have it yet
+    pub(crate) fn add_sanitizer_attributes<'ctx>(&self, module: &Module<'ctx>) -> Vec<FunctionValue<'ctx>> {
+        let sanitizer = match self.sanitizer.get() {
+            Some(sanitizer) => sanitizer,
+            None => return Vec::new(),
+        };
+        let kind_id = Attribute::get_named_enum_kind_id(sanitizer.attribute_name());
+        let attribute = module.get_context().create_enum_attribute(kind_id, 0);
+        let functions: Vec<_> = module
+            .get_functions()
+            .filter(|function| function.count_basic_blocks() > 0)
+            .filter(|function| function.get_enum_attribute(AttributeLoc::Function, kind_id).is_none())
+            .collect();
+
+        for function in &functions {
+            function.add_attribute(AttributeLoc::Function, attribute);
+        }
+
+        functions
+    }
+
+    // Undoes add_sanitizer_attributes when the pipeline could not be run
+    pub(crate) fn remove_sanitizer_attributes(&self, functions: &[FunctionValue]) {
+        if let Some(sanitizer) = self.sanitizer.get() {
+            let kind_id = Attribute::get_named_enum_kind_id(sanitizer.attribute_name());
+
+            for function in functions {
+                function.remove_enum_attribute(AttributeLoc::Function, kind_id);
+            }
+        }
+    }
+
+    // Applies the options which act on the module once the pipeline has run
+    pub(crate) fn finish(&self, module: &Module) {
+        if let Some(section) = &*self.cold_section.borrow() {
+            let kind_id = Attribute::get_named_enum_kind_id("cold");
+
+            for function in module.get_functions() {
+                let is_cold = function.get_enum_attribute(AttributeLoc::Function, kind_id).is_some();
+
+                if is_cold && function.count_basic_blocks() > 0 && function.get_section().is_none() {
+                    function.set_section(Some(section));
+                }
             }
         }
     }
@@ -0,0 +1,1194 @@
+//! The features each target knows of, for validating `TargetFeatures` without creating a target
+//! machine. The C API offers no way to list them, so these are taken from
+//! `llc -march=<target> -mattr=help` of LLVM 14.
+
+const AARCH64: &[&str] = &[
+    "CONTEXTIDREL2",
+    "a35",
+    "a510",
+    "a53",
+    "a55",
+    "a57",
+    "a64fx",
+    "a65",
+    "a710",
+    "a72",
+    "a73",
+    "a75",
+    "a76",
+    "a77",
+    "a78",
+    "a78c",
+    "aes",
+    "aggressive-fma",
+    "alternate-sextload-cvt-f32-pattern",
+    "altnzcv",
+    "am",
+    "ampere1",
+    "amvs",
+    "apple-a10",
+    "apple-a11",
+    "apple-a12",
+    "apple-a13",
+    "apple-a14",
+    "apple-a7",
+    "apple-a7-sysreg",
+    "arith-bcc-fusion",
+    "arith-cbz-fusion",
+    "balance-fp-ops",
+    "bf16",
+    "brbe",
+    "bti",
+    "call-saved-x10",
+    "call-saved-x11",
+    "call-saved-x12",
+    "call-saved-x13",
+    "call-saved-x14",
+    "call-saved-x15",
+    "call-saved-x18",
+    "call-saved-x8",
+    "call-saved-x9",
+    "carmel",
+    "ccdp",
+    "ccidx",
+    "ccpp",
+    "cmp-bcc-fusion",
+    "complxnum",
+    "cortex-r82",
+    "cortex-x1",
+    "cortex-x2",
+    "crc",
+    "crypto",
+    "custom-cheap-as-move",
+    "disable-latency-sched-heuristic",
+    "dit",
+    "dotprod",
+    "ecv",
+    "el2vmsa",
+    "el3",
+    "ete",
+    "exynos-cheap-as-move",
+    "exynosm3",
+    "f32mm",
+    "f64mm",
+    "falkor",
+    "fgt",
+    "fix-cortex-a53-835769",
+    "flagm",
+    "force-32bit-jump-tables",
+    "fp-armv8",
+    "fp16fml",
+    "fptoint",
+    "fullfp16",
+    "fuse-address",
+    "fuse-aes",
+    "fuse-arith-logic",
+    "fuse-crypto-eor",
+    "fuse-csel",
+    "fuse-literals",
+    "harden-sls-blr",
+    "harden-sls-nocomdat",
+    "harden-sls-retbr",
+    "hbc",
+    "hcx",
+    "i8mm",
+    "jsconv",
+    "kryo",
+    "lor",
+    "ls64",
+    "lse",
+    "lse2",
+    "lsl-fast",
+    "mops",
+    "mpam",
+    "mte",
+    "neon",
+    "neoverse512tvb",
+    "neoversee1",
+    "neoversen1",
+    "neoversen2",
+    "neoversev1",
+    "no-bti-at-return-twice",
+    "no-neg-immediates",
+    "no-zcz-fp",
+    "nv",
+    "outline-atomics",
+    "pan",
+    "pan-rwv",
+    "pauth",
+    "perfmon",
+    "predictable-select-expensive",
+    "predres",
+    "rand",
+    "ras",
+    "rcpc",
+    "rcpc-immo",
+    "rdm",
+    "reserve-x1",
+    "reserve-x10",
+    "reserve-x11",
+    "reserve-x12",
+    "reserve-x13",
+    "reserve-x14",
+    "reserve-x15",
+    "reserve-x18",
+    "reserve-x2",
+    "reserve-x20",
+    "reserve-x21",
+    "reserve-x22",
+    "reserve-x23",
+    "reserve-x24",
+    "reserve-x25",
+    "reserve-x26",
+    "reserve-x27",
+    "reserve-x28",
+    "reserve-x3",
+    "reserve-x30",
+    "reserve-x4",
+    "reserve-x5",
+    "reserve-x6",
+    "reserve-x7",
+    "reserve-x9",
+    "rme",
+    "saphira",
+    "sb",
+    "sel2",
+    "sha2",
+    "sha3",
+    "slow-misaligned-128store",
+    "slow-paired-128",
+    "slow-strqro-store",
+    "sm4",
+    "sme",
+    "sme-f64",
+    "sme-i64",
+    "spe",
+    "spe-eef",
+    "specrestrict",
+    "ssbs",
+    "streaming-sve",
+    "strict-align",
+    "sve",
+    "sve2",
+    "sve2-aes",
+    "sve2-bitperm",
+    "sve2-sha3",
+    "sve2-sm4",
+    "tagged-globals",
+    "thunderx",
+    "thunderx2t99",
+    "thunderx3t110",
+    "thunderxt81",
+    "thunderxt83",
+    "thunderxt88",
+    "tlb-rmi",
+    "tme",
+    "tpidr-el1",
+    "tpidr-el2",
+    "tpidr-el3",
+    "tracev8.4",
+    "trbe",
+    "tsv110",
+    "uaops",
+    "use-experimental-zeroing-pseudos",
+    "use-postra-scheduler",
+    "use-reciprocal-square-root",
+    "use-scalar-inc-vl",
+    "v8.1a",
+    "v8.2a",
+    "v8.3a",
+    "v8.4a",
+    "v8.5a",
+    "v8.6a",
+    "v8.7a",
+    "v8.8a",
+    "v8a",
+    "v8r",
+    "v9.1a",
+    "v9.2a",
+    "v9.3a",
+    "v9a",
+    "vh",
+    "wfxt",
+    "xs",
+    "zcm",
+    "zcz",
+    "zcz-fp-workaround",
+    "zcz-gp",
+];
+
+const AMDGCN: &[&str] = &[
+    "16-bit-insts",
+    "DumpCode",
+    "a16",
+    "add-no-carry-insts",
+    "aperture-regs",
+    "architected-flat-scratch",
+    "atomic-fadd-insts",
+    "auto-waitcnt-before-barrier",
+    "ci-insts",
+    "cumode",
+    "dl-insts",
+    "dot1-insts",
+    "dot2-insts",
+    "dot3-insts",
+    "dot4-insts",
+    "dot5-insts",
+    "dot6-insts",
+    "dot7-insts",
+    "dpp",
+    "dpp-64bit",
+    "dpp8",
+    "ds-src2-insts",
+    "dumpcode",
+    "enable-ds128",
+    "enable-prt-strict-null",
+    "extended-image-insts",
+    "fast-denormal-f32",
+    "fast-fmaf",
+    "flat-address-space",
+    "flat-for-global",
+    "flat-global-insts",
+    "flat-inst-offsets",
+    "flat-scratch-insts",
+    "flat-segment-offset-bug",
+    "fma-mix-insts",
+    "fmaf",
+    "fp64",
+    "full-rate-64-ops",
+    "g16",
+    "gcn3-encoding",
+    "get-wave-id-inst",
+    "gfx10",
+    "gfx10-3-insts",
+    "gfx10-insts",
+    "gfx10_a-encoding",
+    "gfx10_b-encoding",
+    "gfx7-gfx8-gfx9-insts",
+    "gfx8-insts",
+    "gfx9",
+    "gfx9-insts",
+    "gfx90a-insts",
+    "half-rate-64-ops",
+    "image-gather4-d16-bug",
+    "image-store-d16-bug",
+    "inst-fwd-prefetch-bug",
+    "int-clamp-insts",
+    "inv-2pi-inline-imm",
+    "lds-branch-vmem-war-hazard",
+    "lds-misaligned-bug",
+    "ldsbankcount16",
+    "ldsbankcount32",
+    "load-store-opt",
+    "localmemorysize32768",
+    "localmemorysize65536",
+    "mad-mac-f32-insts",
+    "mad-mix-insts",
+    "mai-insts",
+    "max-private-element-size-16",
+    "max-private-element-size-4",
+    "max-private-element-size-8",
+    "mfma-inline-literal-bug",
+    "mimg-r128",
+    "movrel",
+    "negative-scratch-offset-bug",
+    "negative-unaligned-scratch-offset-bug",
+    "no-data-dep-hazard",
+    "no-sdst-cmpx",
+    "nsa-clause-bug",
+    "nsa-encoding",
+    "nsa-max-size-13",
+    "nsa-max-size-5",
+    "nsa-to-vmem-bug",
+    "offset-3f-bug",
+    "packed-fp32-ops",
+    "packed-tid",
+    "pk-fmac-f16-inst",
+    "promote-alloca",
+    "r128-a16",
+    "s-memrealtime",
+    "s-memtime-inst",
+    "scalar-atomics",
+    "scalar-flat-scratch-insts",
+    "scalar-stores",
+    "sdwa",
+    "sdwa-mav",
+    "sdwa-omod",
+    "sdwa-out-mods-vopc",
+    "sdwa-scalar",
+    "sdwa-sdst",
+    "sea-islands",
+    "sgpr-init-bug",
+    "shader-cycles-register",
+    "si-scheduler",
+    "smem-to-vector-write-hazard",
+    "southern-islands",
+    "sramecc",
+    "sramecc-support",
+    "tgsplit",
+    "trap-handler",
+    "trig-reduced-range",
+    "unaligned-access-mode",
+    "unaligned-buffer-access",
+    "unaligned-ds-access",
+    "unaligned-scratch-access",
+    "unpacked-d16-vmem",
+    "unsafe-ds-offset-folding",
+    "vcmpx-exec-war-hazard",
+    "vcmpx-permlane-hazard",
+    "vgpr-index-mode",
+    "vmem-to-scalar-write-hazard",
+    "volcanic-islands",
+    "vop3-literal",
+    "vop3p",
+    "vscnt",
+    "wavefrontsize16",
+    "wavefrontsize32",
+    "wavefrontsize64",
+    "xnack",
+    "xnack-support",
+];
+
+const ARM: &[&str] = &[
+    "32bit",
+    "8msecext",
+    "a12",
+    "a15",
+    "a17",
+    "a32",
+    "a35",
+    "a5",
+    "a53",
+    "a55",
+    "a57",
+    "a7",
+    "a72",
+    "a73",
+    "a75",
+    "a76",
+    "a77",
+    "a78c",
+    "a8",
+    "a9",
+    "aclass",
+    "acquire-release",
+    "aes",
+    "armv2",
+    "armv2a",
+    "armv3",
+    "armv3m",
+    "armv4",
+    "armv4t",
+    "armv5t",
+    "armv5te",
+    "armv5tej",
+    "armv6",
+    "armv6-m",
+    "armv6j",
+    "armv6k",
+    "armv6kz",
+    "armv6s-m",
+    "armv6t2",
+    "armv7-a",
+    "armv7-m",
+    "armv7-r",
+    "armv7e-m",
+    "armv7k",
+    "armv7s",
+    "armv7ve",
+    "armv8-a",
+    "armv8-m.base",
+    "armv8-m.main",
+    "armv8-r",
+    "armv8.1-a",
+    "armv8.1-m.main",
+    "armv8.2-a",
+    "armv8.3-a",
+    "armv8.4-a",
+    "armv8.5-a",
+    "armv8.6-a",
+    "armv8.7-a",
+    "armv8.8-a",
+    "armv9-a",
+    "armv9.1-a",
+    "armv9.2-a",
+    "armv9.3-a",
+    "avoid-movs-shop",
+    "avoid-partial-cpsr",
+    "bf16",
+    "cde",
+    "cdecp0",
+    "cdecp1",
+    "cdecp2",
+    "cdecp3",
+    "cdecp4",
+    "cdecp5",
+    "cdecp6",
+    "cdecp7",
+    "cheap-predicable-cpsr",
+    "cortex-a710",
+    "cortex-a78",
+    "cortex-x1",
+    "cortex-x1c",
+    "crc",
+    "crypto",
+    "d32",
+    "db",
+    "dfb",
+    "disable-postra-scheduler",
+    "dont-widen-vmovs",
+    "dotprod",
+    "dsp",
+    "execute-only",
+    "expand-fp-mlx",
+    "exynos",
+    "fix-cmse-cve-2021-35465",
+    "fp-armv8",
+    "fp-armv8d16",
+    "fp-armv8d16sp",
+    "fp-armv8sp",
+    "fp16",
+    "fp16fml",
+    "fp64",
+    "fpao",
+    "fpregs",
+    "fpregs16",
+    "fpregs64",
+    "fullfp16",
+    "fuse-aes",
+    "fuse-literals",
+    "harden-sls-blr",
+    "harden-sls-nocomdat",
+    "harden-sls-retbr",
+    "hwdiv",
+    "hwdiv-arm",
+    "i8mm",
+    "iwmmxt",
+    "iwmmxt2",
+    "krait",
+    "kryo",
+    "lob",
+    "long-calls",
+    "loop-align",
+    "m3",
+    "m7",
+    "mclass",
+    "mp",
+    "muxed-units",
+    "mve",
+    "mve.fp",
+    "mve1beat",
+    "mve2beat",
+    "mve4beat",
+    "nacl-trap",
+    "neon",
+    "neon-fpmovs",
+    "neonfp",
+    "neoverse-v1",
+    "no-branch-predictor",
+    "no-bti-at-return-twice",
+    "no-movt",
+    "no-neg-immediates",
+    "noarm",
+    "nonpipelined-vfp",
+    "pacbti",
+    "perfmon",
+    "prefer-ishst",
+    "prefer-vmovsr",
+    "prof-unpr",
+    "r4",
+    "r5",
+    "r52",
+    "r7",
+    "ras",
+    "rclass",
+    "read-tp-hard",
+    "reserve-r9",
+    "ret-addr-stack",
+    "sb",
+    "sha2",
+    "slow-fp-brcc",
+    "slow-load-D-subreg",
+    "slow-odd-reg",
+    "slow-vdup32",
+    "slow-vgetlni32",
+    "slowfpvfmx",
+    "slowfpvmlx",
+    "soft-float",
+    "splat-vfp-neon",
+    "strict-align",
+    "swift",
+    "thumb-mode",
+    "thumb2",
+    "trustzone",
+    "use-misched",
+    "v4t",
+    "v5t",
+    "v5te",
+    "v6",
+    "v6k",
+    "v6m",
+    "v6t2",
+    "v7",
+    "v7clrex",
+    "v8",
+    "v8.1a",
+    "v8.1m.main",
+    "v8.2a",
+    "v8.3a",
+    "v8.4a",
+    "v8.5a",
+    "v8.6a",
+    "v8.7a",
+    "v8.8a",
+    "v8m",
+    "v8m.main",
+    "v9.1a",
+    "v9.2a",
+    "v9.3a",
+    "v9a",
+    "vfp2",
+    "vfp2sp",
+    "vfp3",
+    "vfp3d16",
+    "vfp3d16sp",
+    "vfp3sp",
+    "vfp4",
+    "vfp4d16",
+    "vfp4d16sp",
+    "vfp4sp",
+    "virtualization",
+    "vldn-align",
+    "vmlx-forwarding",
+    "vmlx-hazards",
+    "wide-stride-vfp",
+    "xscale",
+    "zcz",
+];
+
+const AVR: &[&str] = &[
+    "addsubiw",
+    "avr0",
+    "avr1",
+    "avr2",
+    "avr25",
+    "avr3",
+    "avr31",
+    "avr35",
+    "avr4",
+    "avr5",
+    "avr51",
+    "avr6",
+    "avrtiny",
+    "break",
+    "des",
+    "eijmpcall",
+    "elpm",
+    "elpmx",
+    "ijmpcall",
+    "jmpcall",
+    "lpm",
+    "lpmx",
+    "memmappedregs",
+    "movw",
+    "mul",
+    "rmw",
+    "smallstack",
+    "special",
+    "spm",
+    "spmx",
+    "sram",
+    "tinyencoding",
+    "xmega",
+    "xmegau",
+];
+
+const BPF: &[&str] = &["alu32", "dummy", "dwarfris"];
+
+const HEXAGON: &[&str] = &[
+    "audio",
+    "cabac",
+    "compound",
+    "duplex",
+    "hvx",
+    "hvx-ieee-fp",
+    "hvx-length128b",
+    "hvx-length64b",
+    "hvx-qfloat",
+    "hvxv60",
+    "hvxv62",
+    "hvxv65",
+    "hvxv66",
+    "hvxv67",
+    "hvxv68",
+    "hvxv69",
+    "long-calls",
+    "mem_noshuf",
+    "memops",
+    "noreturn-stack-elim",
+    "nvj",
+    "nvs",
+    "packets",
+    "prev65",
+    "reserved-r19",
+    "small-data",
+    "tinycore",
+    "unsafe-fp",
+    "v5",
+    "v55",
+    "v60",
+    "v62",
+    "v65",
+    "v66",
+    "v67",
+    "v68",
+    "v69",
+    "zreg",
+];
+
+const LANAI: &[&str] = &[];
+
+const M68K: &[&str] = &[
+    "isa-68000",
+    "isa-68010",
+    "isa-68020",
+    "isa-68030",
+    "isa-68040",
+    "isa-68060",
+    "reserve-a0",
+    "reserve-a1",
+    "reserve-a2",
+    "reserve-a3",
+    "reserve-a4",
+    "reserve-a5",
+    "reserve-a6",
+    "reserve-d0",
+    "reserve-d1",
+    "reserve-d2",
+    "reserve-d3",
+    "reserve-d4",
+    "reserve-d5",
+    "reserve-d6",
+    "reserve-d7",
+];
+
+const MIPS: &[&str] = &[
+    "abs2008",
+    "cnmips",
+    "cnmipsp",
+    "crc",
+    "dsp",
+    "dspr2",
+    "dspr3",
+    "eva",
+    "fp64",
+    "fpxx",
+    "ginv",
+    "gp64",
+    "long-calls",
+    "micromips",
+    "mips1",
+    "mips16",
+    "mips2",
+    "mips3",
+    "mips32",
+    "mips32r2",
+    "mips32r3",
+    "mips32r5",
+    "mips32r6",
+    "mips3_32",
+    "mips3_32r2",
+    "mips3d",
+    "mips4",
+    "mips4_32",
+    "mips4_32r2",
+    "mips5",
+    "mips5_32r2",
+    "mips64",
+    "mips64r2",
+    "mips64r3",
+    "mips64r5",
+    "mips64r6",
+    "msa",
+    "mt",
+    "nan2008",
+    "noabicalls",
+    "nomadd4",
+    "nooddspreg",
+    "p5600",
+    "ptr64",
+    "single-float",
+    "soft-float",
+    "sym32",
+    "use-indirect-jump-hazard",
+    "use-tcc-in-div",
+    "vfpu",
+    "virt",
+    "xgot",
+];
+
+const MSP430: &[&str] = &["ext", "hwmult16", "hwmult32", "hwmultf5"];
+
+const NVPTX: &[&str] = &[
+    "ptx32", "ptx40", "ptx41", "ptx42", "ptx43", "ptx50", "ptx60", "ptx61", "ptx63", "ptx64", "ptx65", "ptx70",
+    "ptx71", "ptx72", "ptx73", "ptx74", "ptx75", "sm_20", "sm_21", "sm_30", "sm_32", "sm_35", "sm_37", "sm_50",
+    "sm_52", "sm_53", "sm_60", "sm_61", "sm_62", "sm_70", "sm_72", "sm_75", "sm_80", "sm_86",
+];
+
+const POWERPC: &[&str] = &[
+    "64bit",
+    "64bitregs",
+    "aix",
+    "allow-unaligned-fp-access",
+    "altivec",
+    "booke",
+    "bpermd",
+    "cmpb",
+    "crbits",
+    "crypto",
+    "direct-move",
+    "e500",
+    "efpu2",
+    "extdiv",
+    "fcpsgn",
+    "float128",
+    "fpcvt",
+    "fprnd",
+    "fpu",
+    "fre",
+    "fres",
+    "frsqrte",
+    "frsqrtes",
+    "fsqrt",
+    "fuse-add-logical",
+    "fuse-addi-load",
+    "fuse-addis-load",
+    "fuse-arith-add",
+    "fuse-back2back",
+    "fuse-cmp",
+    "fuse-logical",
+    "fuse-logical-add",
+    "fuse-sha3",
+    "fuse-store",
+    "fuse-wideimm",
+    "fuse-zeromove",
+    "fusion",
+    "hard-float",
+    "htm",
+    "icbt",
+    "invariant-function-descriptors",
+    "isa-v206-instructions",
+    "isa-v207-instructions",
+    "isa-v30-instructions",
+    "isa-v31-instructions",
+    "isel",
+    "ldbrx",
+    "lfiwax",
+    "longcall",
+    "mfocrf",
+    "mma",
+    "modern-aix-as",
+    "msync",
+    "paired-vector-memops",
+    "partword-atomics",
+    "pcrelative-memops",
+    "popcntd",
+    "power10-vector",
+    "power8-altivec",
+    "power8-vector",
+    "power9-altivec",
+    "power9-vector",
+    "ppc-postra-sched",
+    "ppc-prera-sched",
+    "ppc4xx",
+    "ppc6xx",
+    "predictable-select-expensive",
+    "prefix-instrs",
+    "privileged",
+    "quadword-atomics",
+    "recipprec",
+    "rop-protect",
+    "secure-plt",
+    "slow-popcntd",
+    "spe",
+    "stfiwx",
+    "two-const-nr",
+    "vectors-use-two-units",
+    "vsx",
+];
+
+const R600: &[&str] = &[
+    "HasVertexCache",
+    "R600ALUInst",
+    "caymanISA",
+    "cfalubug",
+    "evergreen",
+    "fetch16",
+    "fetch8",
+    "fmaf",
+    "fp64",
+    "localmemorysize32768",
+    "localmemorysize65536",
+    "northern-islands",
+    "promote-alloca",
+    "r600",
+    "r700",
+    "wavefrontsize16",
+    "wavefrontsize32",
+    "wavefrontsize64",
+];
+
+const RISCV: &[&str] = &[
+    "64bit",
+    "a",
+    "c",
+    "d",
+    "e",
+    "experimental-zbe",
+    "experimental-zbf",
+    "experimental-zbm",
+    "experimental-zbp",
+    "experimental-zbr",
+    "experimental-zbt",
+    "f",
+    "m",
+    "no-rvc-hints",
+    "relax",
+    "reserve-x1",
+    "reserve-x10",
+    "reserve-x11",
+    "reserve-x12",
+    "reserve-x13",
+    "reserve-x14",
+    "reserve-x15",
+    "reserve-x16",
+    "reserve-x17",
+    "reserve-x18",
+    "reserve-x19",
+    "reserve-x2",
+    "reserve-x20",
+    "reserve-x21",
+    "reserve-x22",
+    "reserve-x23",
+    "reserve-x24",
+    "reserve-x25",
+    "reserve-x26",
+    "reserve-x27",
+    "reserve-x28",
+    "reserve-x29",
+    "reserve-x3",
+    "reserve-x30",
+    "reserve-x31",
+    "reserve-x4",
+    "reserve-x5",
+    "reserve-x6",
+    "reserve-x7",
+    "reserve-x8",
+    "reserve-x9",
+    "save-restore",
+    "sifive7",
+    "v",
+    "zba",
+    "zbb",
+    "zbc",
+    "zbkb",
+    "zbkc",
+    "zbkx",
+    "zbs",
+    "zdinx",
+    "zfh",
+    "zfhmin",
+    "zfinx",
+    "zhinx",
+    "zhinxmin",
+    "zk",
+    "zkn",
+    "zknd",
+    "zkne",
+    "zknh",
+    "zkr",
+    "zks",
+    "zksed",
+    "zksh",
+    "zkt",
+    "zve32f",
+    "zve32x",
+    "zve64d",
+    "zve64f",
+    "zve64x",
+    "zvl1024b",
+    "zvl128b",
+    "zvl16384b",
+    "zvl2048b",
+    "zvl256b",
+    "zvl32768b",
+    "zvl32b",
+    "zvl4096b",
+    "zvl512b",
+    "zvl64b",
+    "zvl8192b",
+];
+
+const SPARC: &[&str] = &[
+    "deprecated-v8",
+    "detectroundchange",
+    "fixallfdivsqrt",
+    "hard-quad-float",
+    "hasleoncasa",
+    "hasumacsmac",
+    "insertnopload",
+    "leon",
+    "leoncyclecounter",
+    "leonpwrpsr",
+    "no-fmuls",
+    "no-fsmuld",
+    "popc",
+    "soft-float",
+    "soft-mul-div",
+    "v9",
+    "vis",
+    "vis2",
+    "vis3",
+];
+
+const SYSTEMZ: &[&str] = &[
+    "bear-enhancement",
+    "deflate-conversion",
+    "dfp-packed-conversion",
+    "dfp-zoned-conversion",
+    "distinct-ops",
+    "enhanced-dat-2",
+    "enhanced-sort",
+    "execution-hint",
+    "fast-serialization",
+    "fp-extension",
+    "guarded-storage",
+    "high-word",
+    "insert-reference-bits-multiple",
+    "interlocked-access1",
+    "load-and-trap",
+    "load-and-zero-rightmost-byte",
+    "load-store-on-cond",
+    "load-store-on-cond-2",
+    "message-security-assist-extension3",
+    "message-security-assist-extension4",
+    "message-security-assist-extension5",
+    "message-security-assist-extension7",
+    "message-security-assist-extension8",
+    "message-security-assist-extension9",
+    "miscellaneous-extensions",
+    "miscellaneous-extensions-2",
+    "miscellaneous-extensions-3",
+    "nnp-assist",
+    "population-count",
+    "processor-activity-instrumentation",
+    "processor-assist",
+    "reset-dat-protection",
+    "reset-reference-bits-multiple",
+    "soft-float",
+    "transactional-execution",
+    "vector",
+    "vector-enhancements-1",
+    "vector-enhancements-2",
+    "vector-packed-decimal",
+    "vector-packed-decimal-enhancement",
+    "vector-packed-decimal-enhancement-2",
+];
+
+const VE: &[&str] = &["vpu"];
+
+const WEBASSEMBLY: &[&str] = &[
+    "atomics",
+    "bulk-memory",
+    "exception-handling",
+    "multivalue",
+    "mutable-globals",
+    "nontrapping-fptoint",
+    "reference-types",
+    "relaxed-simd",
+    "sign-ext",
+    "simd128",
+    "tail-call",
+];
+
+const X86: &[&str] = &[
+    "16bit-mode",
+    "32bit-mode",
+    "3dnow",
+    "3dnowa",
+    "64bit",
+    "64bit-mode",
+    "adx",
+    "aes",
+    "amx-bf16",
+    "amx-int8",
+    "amx-tile",
+    "avx",
+    "avx2",
+    "avx512bf16",
+    "avx512bitalg",
+    "avx512bw",
+    "avx512cd",
+    "avx512dq",
+    "avx512er",
+    "avx512f",
+    "avx512fp16",
+    "avx512ifma",
+    "avx512pf",
+    "avx512vbmi",
+    "avx512vbmi2",
+    "avx512vl",
+    "avx512vnni",
+    "avx512vp2intersect",
+    "avx512vpopcntdq",
+    "avxvnni",
+    "bmi",
+    "bmi2",
+    "branchfusion",
+    "cldemote",
+    "clflushopt",
+    "clwb",
+    "clzero",
+    "cmov",
+    "crc32",
+    "cx16",
+    "cx8",
+    "enqcmd",
+    "ermsb",
+    "f16c",
+    "false-deps-lzcnt-tzcnt",
+    "false-deps-popcnt",
+    "fast-11bytenop",
+    "fast-15bytenop",
+    "fast-7bytenop",
+    "fast-bextr",
+    "fast-gather",
+    "fast-hops",
+    "fast-lzcnt",
+    "fast-movbe",
+    "fast-scalar-fsqrt",
+    "fast-scalar-shift-masks",
+    "fast-shld-rotate",
+    "fast-variable-crosslane-shuffle",
+    "fast-variable-perlane-shuffle",
+    "fast-vector-fsqrt",
+    "fast-vector-shift-masks",
+    "fma",
+    "fma4",
+    "fsgsbase",
+    "fsrm",
+    "fxsr",
+    "gfni",
+    "hreset",
+    "idivl-to-divb",
+    "idivq-to-divl",
+    "invpcid",
+    "kl",
+    "lea-sp",
+    "lea-uses-ag",
+    "lvi-cfi",
+    "lvi-load-hardening",
+    "lwp",
+    "lzcnt",
+    "macrofusion",
+    "mmx",
+    "movbe",
+    "movdir64b",
+    "movdiri",
+    "mwaitx",
+    "nopl",
+    "pad-short-functions",
+    "pclmul",
+    "pconfig",
+    "pku",
+    "popcnt",
+    "prefer-128-bit",
+    "prefer-256-bit",
+    "prefer-mask-registers",
+    "prefetchwt1",
+    "prfchw",
+    "ptwrite",
+    "rdpid",
+    "rdrnd",
+    "rdseed",
+    "retpoline",
+    "retpoline-external-thunk",
+    "retpoline-indirect-branches",
+    "retpoline-indirect-calls",
+    "rtm",
+    "sahf",
+    "serialize",
+    "seses",
+    "sgx",
+    "sha",
+    "shstk",
+    "slow-3ops-lea",
+    "slow-incdec",
+    "slow-lea",
+    "slow-pmaddwd",
+    "slow-pmulld",
+    "slow-shld",
+    "slow-two-mem-ops",
+    "slow-unaligned-mem-16",
+    "slow-unaligned-mem-32",
+    "soft-float",
+    "sse",
+    "sse-unaligned-mem",
+    "sse2",
+    "sse3",
+    "sse4.1",
+    "sse4.2",
+    "sse4a",
+    "ssse3",
+    "tagged-globals",
+    "tbm",
+    "tsxldtrk",
+    "uintr",
+    "use-aa",
+    "use-glm-div-sqrt-costs",
+    "use-slm-arith-costs",
+    "vaes",
+    "vpclmulqdq",
+    "vzeroupper",
+    "waitpkg",
+    "wbnoinvd",
+    "widekl",
+    "x87",
+    "xop",
+    "xsave",
+    "xsavec",
+    "xsaveopt",
+    "xsaves",
+];
+
+const XCORE: &[&str] = &[];
+
+/// Gets the features of the target named `target_name`, as given by `Target::get_name`, or `None`
+/// if the target is unknown.
+pub(crate) fn known_target_features(target_name: &str) -> Option<&'static [&'static str]> {
+    let features = match target_name {
+        "aarch64" | "aarch64_32" | "aarch64_be" | "arm64" | "arm64_32" => AARCH64,
+        "amdgcn" => AMDGCN,
+        "arm" | "armeb" | "thumb" | "thumbeb" => ARM,
+        "avr" => AVR,
+        "bpf" | "bpfeb" | "bpfel" => BPF,
+        "hexagon" => HEXAGON,
+        "lanai" => LANAI,
+        "m68k" => M68K,
+        "mips" | "mipsel" | "mips64" | "mips64el" => MIPS,
+        "msp430" => MSP430,
+        "nvptx" | "nvptx64" => NVPTX,
+        "ppc32" | "ppc32le" | "ppc64" | "ppc64le" => POWERPC,
+        "r600" => R600,
+        "riscv32" | "riscv64" => RISCV,
+        "sparc" | "sparcel" | "sparcv9" => SPARC,
+        "systemz" => SYSTEMZ,
+        "ve" => VE,
+        "wasm32" | "wasm64" => WEBASSEMBLY,
+        "x86" | "x86-64" => X86,
+        "xcore" => XCORE,
+        _ => return None,
+    };
+
+    Some(features)
+}
@@ -32,6 +32,7 @@ use crate::LLVMReference;
 use crate::{AddressSpace, OptimizationLevel};
 
 use std::default::Default;
+use std::error::Error;
 use std::ffi::CStr;
 use std::fmt;
 use std::mem::MaybeUninit;
@@ -140,6 +141,146 @@ impl fmt::Display for TargetTriple {
     }
 }
 
+/// Builds a target feature string, such as `+simd128,-tail-call`, for
+/// `Target::create_target_machine`.
+///
+/// LLVM ignores features it does not recognize, only printing a warning, so a misspelled
+/// feature silently does nothing. With LLVM 14, `TargetFeatures::validate` catches those ahead
+/// of time.
+///
+/// # Example
+///
+/// ```no_run
+/// use inkwell::targets::{InitializationConfig, Target, TargetFeatures, TargetFeaturesError, TargetTriple};
+///
+/// Target::initialize_webassembly(&InitializationConfig::default());
+///
+/// let triple = TargetTriple::create("wasm32-unknown-unknown");
+/// let target = Target::from_triple(&triple).unwrap();
+/// let features = TargetFeatures::new().enable("simd128").disable("tail-call");
+///
+/// assert_eq!(features.to_string(), "+simd128,-tail-call");
+/// assert!(features.validate(&target).is_ok());
+/// assert_eq!(
+///     TargetFeatures::new().enable("simd-128").validate(&target),
+///     Err(TargetFeaturesError::Unrecognized(vec!["+simd-128".to_owned()]))
+/// );
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TargetFeatures {
+    features: Vec<(String, bool)>,
+}
+
+impl TargetFeatures {
+    /// Creates an empty set of features, leaving the target's defaults in place.
+    pub fn new() -> Self {
+        TargetFeatures::default()
+    }
+
+    /// Enables `feature`, given without a leading `+`. This replaces an earlier
+    /// `enable` or `disable` of the same feature.
+    pub fn enable(self, feature: &str) -> Self {
+        self.set(feature, true)
+    }
+
+    /// Disables `feature`, given without a leading `-`. This replaces an earlier
+    /// `enable` or `disable` of the same feature.
+    pub fn disable(self, feature: &str) -> Self {
+        self.set(feature, false)
+    }
+
+    fn set(mut self, feature: &str, enabled: bool) -> Self {
+        self.features.retain(|(name, _)| name != feature);
+        self.features.push((feature.to_owned(), enabled));
+        self
+    }
+
+    /// Checks that every feature is well formed and known to `target`.
+    ///
+    /// The C API can't list a target's features, so they are checked against the feature
+    /// tables of LLVM 14, which inkwell carries. This is only supported with LLVM 14: with
+    /// other versions, or targets without a table, the feature names are checked and then
+    /// `TargetFeaturesError::CannotValidate` is returned.
+    pub fn validate(&self, target: &Target) -> Result<(), TargetFeaturesError> {
+        if let Some((name, _)) = self.features.iter().find(|(name, _)| {
+            name.is_empty()
+                || name.starts_with(['+', '-'])
+                || name.contains([',', '\0'])
+                || name.contains(char::is_whitespace)
+        }) {
+            return Err(TargetFeaturesError::InvalidName(name.clone()));
+        }
+
+        let known = match known_target_features(target) {
+            Some(known) => known,
+            None => return Err(TargetFeaturesError::CannotValidate),
+        };
+        let unrecognized: Vec<String> = self
+            .features
+            .iter()
+            .filter(|(name, _)| !known.contains(&name.as_str()))
+            .map(|(name, enabled)| format!("{}{}", if *enabled { '+' } else { '-' }, name))
+            .collect();
+
+        if !unrecognized.is_empty() {
+            return Err(TargetFeaturesError::Unrecognized(unrecognized));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "llvm14-0")]
+fn known_target_features(target: &Target) -> Option<&'static [&'static str]> {
+    crate::target_features::known_target_features(&target.get_name().to_string_lossy())
+}
+
+#[cfg(not(feature = "llvm14-0"))]
+fn known_target_features(_target: &Target) -> Option<&'static [&'static str]> {
+    None
+}
+
+impl fmt::Display for TargetFeatures {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, (name, enabled)) in self.features.iter().enumerate() {
+            if i > 0 {
+                f.write_str(",")?;
+            }
+
+            write!(f, "{}{}", if *enabled { '+' } else { '-' }, name)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The reasons `TargetFeatures::validate` can reject a set of features.
+#[derive(Debug, PartialEq, Eq)]
+pub enum TargetFeaturesError {
+    /// A feature name is empty, has a leading `+` or `-`, or contains a comma, nul or whitespace.
+    InvalidName(String),
+    /// The target doesn't know these features, given with their `+` or `-`.
+    Unrecognized(Vec<String>),
+    /// The features are well formed, but can't be checked with this LLVM version or target.
+    CannotValidate,
+}
+
+impl fmt::Display for TargetFeaturesError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TargetFeaturesError::InvalidName(name) => write!(f, "Invalid target feature name: '{}'.", name),
+            TargetFeaturesError::Unrecognized(features) => {
+                write!(f, "Unrecognized target features: {}.", features.join(", "))
+            },
+            TargetFeaturesError::CannotValidate => {
+                f.write_str("Target features can only be validated with LLVM 14, for targets it knows of.")
+            },
+        }
+    }
+}
+
+impl Error for TargetFeaturesError {}
+
 static TARGET_LOCK: Lazy<RwLock<()>> = Lazy::new(|| RwLock::new(()));
 
 // NOTE: Versions verified as target-complete: 3.6, 3.7, 3.8, 3.9, 4.0
@@ -1275,6 +1416,53 @@ impl TargetData {
         }
     }
 
+    /// Gets the address space allocas are placed in, given by the `A` component of the
+    /// data layout. This is `AddressSpace::Generic` on most targets, but not on AMDGPU.
+    ///
+    /// `None` is returned if the data layout names an address space `AddressSpace` has no
+    /// variant for.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::targets::TargetData;
+    /// use inkwell::AddressSpace;
+    ///
+    /// let target_data = TargetData::create("e-p:64:64-p3:32:32-p5:32:32-A5-G1");
+    ///
+    /// assert_eq!(target_data.get_alloca_address_space(), Some(AddressSpace::AMDGPU_PRIVATE));
+    /// assert_eq!(target_data.get_globals_address_space(), Some(AddressSpace::AMDGPU_GLOBAL));
+    /// assert_eq!(target_data.get_program_address_space(), Some(AddressSpace::Generic));
+    /// assert_eq!(target_data.get_pointer_byte_size(Some(AddressSpace::AMDGPU_LOCAL)), 4);
+    /// ```
+    pub fn get_alloca_address_space(&self) -> Option<AddressSpace> {
+        self.get_layout_address_space('A')
+    }
+
+    /// Gets the address space globals are created in by default, given by the `G`
+    /// component of the data layout, or `None` if `AddressSpace` has no variant for it.
+    pub fn get_globals_address_space(&self) -> Option<AddressSpace> {
+        self.get_layout_address_space('G')
+    }
+
+    /// Gets the address space functions live in, given by the `P` component of the data
+    /// layout, or `None` if `AddressSpace` has no variant for it.
+    pub fn get_program_address_space(&self) -> Option<AddressSpace> {
+        self.get_layout_address_space('P')
+    }
+
+    fn get_layout_address_space(&self, component: char) -> Option<AddressSpace> {
+        let data_layout = self.get_data_layout();
+        let address_space = data_layout
+            .as_str()
+            .to_string_lossy()
+            .split('-')
+            .find_map(|spec| spec.strip_prefix(component)?.parse().ok())
+            .unwrap_or(0);
+
+        AddressSpace::try_from(address_space).ok()
+    }
+
     pub fn get_store_size(&self, type_: &dyn AnyType) -> u64 {
         unsafe { LLVMStoreSizeOfType(self.target_data, type_.as_type_ref()) }
     }
@@ -0,0 +1,128 @@
+//! Helpers for writing golden IR tests against modules built with inkwell.
+//!
+//! Comparing `Module::print_to_string` output directly is brittle: whitespace, comments,
+//! attribute group numbering and the module header all leak into the comparison. The
+//! functions here parse both sides in a fresh `Context` and print them back out, so only
+//! differences LLVM itself considers meaningful remain.
+
+use crate::context::Context;
+use crate::memory_buffer::MemoryBuffer;
+use crate::module::Module;
+use crate::support::LLVMString;
+
+use std::fmt::Write;
+
+/// Parses `ir` in a fresh `Context` and prints it back out. The `; ModuleID` comment and
+/// the `source_filename` line are dropped, since they depend on where the IR came from
+/// rather than what it contains.
+///
+/// # Example
+///
+/// ```no_run
+/// use inkwell::testing::canonicalize_ir;
+///
+/// let canonical = canonicalize_ir("define   void @f()   {\n  ret void ; done\n}").unwrap();
+///
+/// assert_eq!(canonical, "\ndefine void @f() {\n  ret void\n}\n");
+/// ```
+pub fn canonicalize_ir(ir: &str) -> Result<String, LLVMString> {
+    let context = Context::create();
+    let memory_buffer = MemoryBuffer::create_from_memory_range_copy(ir.as_bytes(), "canonical");
+    let module = context.create_module_from_ir(memory_buffer)?;
+    let printed = module.print_to_string().to_string();
+
+    let mut canonical = String::with_capacity(printed.len());
+
+    for line in printed.lines() {
+        if line.starts_with("; ModuleID") || line.starts_with("source_filename") {
+            continue;
+        }
+
+        canonical.push_str(line);
+        canonical.push('\n');
+    }
+
+    Ok(canonical)
+}
+
+/// Compares the IR of `module` with the `expected` textual IR after canonicalizing both
+/// with `canonicalize_ir`. On a mismatch the error holds a line diff where lines only in
+/// `expected` are prefixed with `-` and lines only in `module` with `+`.
+///
+/// # Example
+///
+/// ```no_run
+/// use inkwell::context::Context;
+/// use inkwell::testing::diff_module_ir;
+///
+/// let context = Context::create();
+/// let module = context.create_module("my_module");
+/// let builder = context.create_builder();
+/// let fn_value = module.add_function("f", context.void_type().fn_type(&[], false), None);
+///
+/// builder.position_at_end(context.append_basic_block(fn_value, "entry"));
+/// builder.build_return(None);
+///
+/// assert!(diff_module_ir(&module, "define void @f() {\nentry:\n  ret void\n}").is_ok());
+/// assert!(diff_module_ir(&module, "define void @g() {\nentry:\n  ret void\n}").is_err());
+/// ```
+pub fn diff_module_ir(module: &Module, expected: &str) -> Result<(), String> {
+    let actual = canonicalize_ir(module.print_to_string().to_str().map_err(|e| e.to_string())?)
+        .map_err(|e| format!("Could not parse module IR: {}", e))?;
+    let expected = canonicalize_ir(expected).map_err(|e| format!("Could not parse expected IR: {}", e))?;
+
+    if actual == expected {
+        return Ok(());
+    }
+
+    Err(diff_lines(&expected, &actual))
+}
+
+/// Asserts that the IR of `module` matches the `expected` textual IR, as determined by
+/// `diff_module_ir`.
+///
+/// # Panics
+///
+/// Panics with the line diff if the IR differs, or if either side fails to parse.
+pub fn assert_module_ir_eq(module: &Module, expected: &str) {
+    if let Err(diff) = diff_module_ir(module, expected) {
+        panic!("module IR does not match the expected IR:\n{}", diff);
+    }
+}
+
+// Longest common subsequence line diff. Golden IR tests compare small modules, so the
+// quadratic table is not a concern here.
+fn diff_lines(expected: &str, actual: &str) -> String {
+    let expected: Vec<&str> = expected.lines().collect();
+    let actual: Vec<&str> = actual.lines().collect();
+    let mut lengths = vec![vec![0usize; actual.len() + 1]; expected.len() + 1];
+
+    for i in (0..expected.len()).rev() {
+        for j in (0..actual.len()).rev() {
+            lengths[i][j] = if expected[i] == actual[j] {
+                lengths[i + 1][j + 1] + 1
+            } else {
+                lengths[i + 1][j].max(lengths[i][j + 1])
+            };
+        }
+    }
+
+    let mut diff = String::new();
+    let (mut i, mut j) = (0, 0);
+
+    while i < expected.len() || j < actual.len() {
+        if i < expected.len() && j < actual.len() && expected[i] == actual[j] {
+            let _ = writeln!(diff, "  {}", expected[i]);
+            i += 1;
+            j += 1;
+        } else if j < actual.len() && (i == expected.len() || lengths[i][j + 1] >= lengths[i + 1][j]) {
+            let _ = writeln!(diff, "+ {}", actual[j]);
+            j += 1;
+        } else {
+            let _ = writeln!(diff, "- {}", expected[i]);
+            i += 1;
+        }
+    }
+
+    diff
+}
@@ -187,6 +187,128 @@ impl<'ctx> FunctionType<'ctx> {
         unsafe { Some(BasicTypeEnum::new(ty)) }
     }
 
+    /// Checks that `constraints` is a valid inline assembly constraint string for
+    /// this `FunctionType`, following the same rules as LLVM's `InlineAsm::Verify`.
+    /// The C API does not perform this check when inline assembly is created, so a
+    /// mismatched constraint string would otherwise only fail during code generation.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let i64_type = context.i64_type();
+    /// let fn_type = i64_type.fn_type(&[i64_type.into(), i64_type.into()], false);
+    ///
+    /// assert!(fn_type.verify_inline_asm_constraints("=r,{rax},{rdi}").is_ok());
+    /// assert!(fn_type.verify_inline_asm_constraints("=r,{rax}").is_err());
+    /// ```
+    pub fn verify_inline_asm_constraints(self, constraints: &str) -> Result<(), &'static str> {
+        if self.is_var_arg() {
+            return Err("Inline assembly cannot have a variadic function type.");
+        }
+
+        let mut num_outputs = 0;
+        let mut num_inputs = 0;
+        let mut num_indirect = 0;
+        let mut num_clobbers = 0;
+
+        if !constraints.is_empty() {
+            for code in constraints.split(',') {
+                let mut chars = code.chars().peekable();
+
+                match chars.peek() {
+                    Some('~') => {
+                        chars.next();
+                        num_clobbers += 1;
+                    },
+                    Some('=') => {
+                        chars.next();
+
+                        if num_inputs - num_indirect != 0 || num_clobbers != 0 {
+                            return Err("Output constraints must precede input and clobber constraints.");
+                        }
+
+                        chars.next_if_eq(&'&');
+
+                        if chars.next_if_eq(&'*').is_some() {
+                            num_indirect += 1;
+                            num_inputs += 1;
+                        } else {
+                            num_outputs += 1;
+                        }
+                    },
+                    Some(_) => {
+                        if num_clobbers != 0 {
+                            return Err("Input constraints must precede clobber constraints.");
+                        }
+
+                        chars.next_if_eq(&'*');
+                        num_inputs += 1;
+                    },
+                    None => return Err("Inline assembly constraints cannot be empty."),
+                }
+
+                let mut has_code = false;
+
+                while let Some(c) = chars.next() {
+                    match c {
+                        '{' if !chars.by_ref().any(|c| c == '}') => {
+                            return Err("Unterminated register name in inline assembly constraint.");
+                        },
+                        '0'..='9' => {
+                            let mut index = c.to_digit(10).unwrap();
+
+                            while let Some(digit) = chars.peek().and_then(|c| c.to_digit(10)) {
+                                index = index * 10 + digit;
+                                chars.next();
+                            }
+
+                            if index >= num_outputs {
+                                return Err("Matching inline assembly constraint does not refer to an output.");
+                            }
+                        },
+                        '|' => {
+                            if !has_code {
+                                return Err("Inline assembly constraint alternative cannot be empty.");
+                            }
+
+                            has_code = false;
+
+                            continue;
+                        },
+                        '^' if chars.next().is_none() || chars.next().is_none() => {
+                            return Err("Incomplete two letter inline assembly constraint.");
+                        },
+                        '}' | '=' | '~' | '*' => return Err("Unexpected character in inline assembly constraint."),
+                        _ => {},
+                    }
+
+                    has_code = true;
+                }
+
+                if !has_code {
+                    return Err("Inline assembly constraints cannot be empty.");
+                }
+            }
+        }
+
+        match (num_outputs, self.get_return_type()) {
+            (0, None) => {},
+            (0, Some(_)) => return Err("Inline assembly without outputs must return void."),
+            (1, Some(ty)) if !ty.is_struct_type() => {},
+            (n, Some(BasicTypeEnum::StructType(ty))) if n > 1 && ty.count_fields() == n => {},
+            _ => return Err("Inline assembly return type does not match its output constraints."),
+        }
+
+        if self.count_param_types() != num_inputs {
+            return Err("Inline assembly parameter types do not match its input constraints.");
+        }
+
+        Ok(())
+    }
+
     // REVIEW: Can you do undef for functions?
     // Seems to "work" - no UB or SF so far but fails
     // LLVMIsAFunction() check. Commenting out for further research
@@ -4,10 +4,11 @@ use llvm_sys::core::{
     LLVMRemoveEnumAttributeAtIndex, LLVMRemoveStringAttributeAtIndex,
 };
 use llvm_sys::core::{
-    LLVMCountBasicBlocks, LLVMCountParams, LLVMDeleteFunction, LLVMGetBasicBlocks, LLVMGetFirstBasicBlock,
-    LLVMGetFirstParam, LLVMGetFunctionCallConv, LLVMGetGC, LLVMGetIntrinsicID, LLVMGetLastBasicBlock, LLVMGetLastParam,
-    LLVMGetLinkage, LLVMGetNextFunction, LLVMGetNextParam, LLVMGetParam, LLVMGetParams, LLVMGetPreviousFunction,
-    LLVMIsAFunction, LLVMIsConstant, LLVMSetFunctionCallConv, LLVMSetGC, LLVMSetLinkage, LLVMSetParamAlignment,
+    LLVMCountBasicBlocks, LLVMCountParams, LLVMDeleteFunction, LLVMGetBasicBlocks, LLVMGetDLLStorageClass,
+    LLVMGetFirstBasicBlock, LLVMGetFirstParam, LLVMGetFunctionCallConv, LLVMGetGC, LLVMGetIntrinsicID,
+    LLVMGetLastBasicBlock, LLVMGetLastParam, LLVMGetLinkage, LLVMGetNextFunction, LLVMGetNextParam, LLVMGetParam,
+    LLVMGetParams, LLVMGetPreviousFunction, LLVMGetVisibility, LLVMIsAFunction, LLVMIsConstant, LLVMSetDLLStorageClass,
+    LLVMSetFunctionCallConv, LLVMSetGC, LLVMSetLinkage, LLVMSetParamAlignment, LLVMSetVisibility,
 };
 use llvm_sys::core::{LLVMGetPersonalityFn, LLVMSetPersonalityFn};
 #[llvm_versions(7.0..=latest)]
@@ -28,6 +29,7 @@ use crate::support::to_c_str;
 use crate::types::{FunctionType, PointerType};
 use crate::values::traits::{AnyValue, AsValueRef};
 use crate::values::{BasicValueEnum, GlobalValue, Value};
+use crate::{DLLStorageClass, GlobalVisibility};
 
 #[derive(PartialEq, Eq, Clone, Copy, Hash)]
 pub struct FunctionValue<'ctx> {
@@ -55,6 +57,62 @@ impl<'ctx> FunctionValue<'ctx> {
         unsafe { LLVMSetLinkage(self.as_value_ref(), linkage.into()) }
     }
 
+    pub fn get_visibility(self) -> GlobalVisibility {
+        let visibility = unsafe { LLVMGetVisibility(self.as_value_ref()) };
+
+        GlobalVisibility::new(visibility)
+    }
+
+    /// Sets the visibility of this function's symbol. `GlobalVisibility::Hidden` keeps it
+    /// out of a shared library's dynamic symbol table, as `-fvisibility=hidden` does.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    /// use inkwell::GlobalVisibility;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_mod");
+    /// let fn_type = context.void_type().fn_type(&[], false);
+    /// let fn_value = module.add_function("my_fn", fn_type, None);
+    ///
+    /// fn_value.set_visibility(GlobalVisibility::Hidden);
+    ///
+    /// assert_eq!(fn_value.get_visibility(), GlobalVisibility::Hidden);
+    /// ```
+    pub fn set_visibility(self, visibility: GlobalVisibility) {
+        unsafe { LLVMSetVisibility(self.as_value_ref(), visibility.into()) }
+    }
+
+    pub fn get_dll_storage_class(self) -> DLLStorageClass {
+        let dll_storage_class = unsafe { LLVMGetDLLStorageClass(self.as_value_ref()) };
+
+        DLLStorageClass::new(dll_storage_class)
+    }
+
+    /// Sets whether this function is imported from or exported by a Windows DLL. Functions
+    /// with a DLL storage class must have default visibility.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    /// use inkwell::DLLStorageClass;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_mod");
+    /// let fn_type = context.void_type().fn_type(&[], false);
+    /// let fn_value = module.add_function("my_fn", fn_type, None);
+    ///
+    /// fn_value.set_dll_storage_class(DLLStorageClass::Import);
+    ///
+    /// assert_eq!(fn_value.get_dll_storage_class(), DLLStorageClass::Import);
+    /// ```
+    pub fn set_dll_storage_class(self, dll_storage_class: DLLStorageClass) {
+        unsafe { LLVMSetDLLStorageClass(self.as_value_ref(), dll_storage_class.into()) }
+    }
+
     pub fn is_null(self) -> bool {
         self.fn_value.is_null()
     }
@@ -131,6 +189,13 @@ impl<'ctx> FunctionValue<'ctx> {
         unsafe { LLVMCountBasicBlocks(self.as_value_ref()) }
     }
 
+    /// Determines whether this function has a body which has not been loaded yet. This can
+    /// only be the case for functions of a lazily parsed module, see
+    /// `Module::parse_bitcode_from_buffer_lazily` and `Module::materialize_function`.
+    pub fn is_materializable(self) -> bool {
+        self.count_basic_blocks() == 0 && !self.as_global_value().is_declaration()
+    }
+
     pub fn get_basic_blocks(self) -> Vec<BasicBlock<'ctx>> {
         let count = self.count_basic_blocks();
         let mut raw_vec: Vec<LLVMBasicBlockRef> = Vec::with_capacity(count as usize);
@@ -224,6 +289,9 @@ impl<'ctx> FunctionValue<'ctx> {
         unsafe { LLVMSetPersonalityFn(self.as_value_ref(), personality_fn.as_value_ref()) }
     }
 
+    // REVIEW: Prefix and prologue data can't be set until the C API exposes
+    // llvm::Function::setPrefixData and setPrologueData
+
     pub fn get_intrinsic_id(self) -> u32 {
         unsafe { LLVMGetIntrinsicID(self.as_value_ref()) }
     }
@@ -273,6 +341,69 @@ impl<'ctx> FunctionValue<'ctx> {
         unsafe { LLVMAddAttributeAtIndex(self.as_value_ref(), loc.get_index(), attribute.attribute) }
     }
 
+    /// Imports this function declaration from the WebAssembly module `module` under `name`,
+    /// by setting the `wasm-import-module` and `wasm-import-name` attributes. Without them,
+    /// undefined functions are imported from the `env` module under their symbol name.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::attributes::AttributeLoc;
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_mod");
+    /// let fn_type = context.void_type().fn_type(&[], false);
+    /// let fn_value = module.add_function("log", fn_type, None);
+    ///
+    /// fn_value.set_wasm_import("console", "log");
+    ///
+    /// let import_module = fn_value.get_string_attribute(AttributeLoc::Function, "wasm-import-module").unwrap();
+    ///
+    /// assert_eq!(import_module.get_string_value().to_str(), Ok("console"));
+    /// ```
+    pub fn set_wasm_import(self, module: &str, name: &str) {
+        let context = self.get_type().get_context();
+
+        self.add_attribute(
+            AttributeLoc::Function,
+            context.create_string_attribute("wasm-import-module", module),
+        );
+        self.add_attribute(
+            AttributeLoc::Function,
+            context.create_string_attribute("wasm-import-name", name),
+        );
+    }
+
+    /// Exports this function from the WebAssembly module under `name` rather than its
+    /// symbol name, by setting the `wasm-export-name` attribute.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::attributes::AttributeLoc;
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_mod");
+    /// let fn_type = context.void_type().fn_type(&[], false);
+    /// let fn_value = module.add_function("my_fn", fn_type, None);
+    ///
+    /// fn_value.set_wasm_export_name("run");
+    ///
+    /// let export_name = fn_value.get_string_attribute(AttributeLoc::Function, "wasm-export-name").unwrap();
+    ///
+    /// assert_eq!(export_name.get_string_value().to_str(), Ok("run"));
+    /// ```
+    pub fn set_wasm_export_name(self, name: &str) {
+        let attribute = self
+            .get_type()
+            .get_context()
+            .create_string_attribute("wasm-export-name", name);
+
+        self.add_attribute(AttributeLoc::Function, attribute);
+    }
+
     /// Counts the number of `Attribute`s belonging to the specified location in this `FunctionValue`.
     ///
     /// # Example
@@ -507,7 +638,8 @@ impl<'ctx> FunctionValue<'ctx> {
         self.fn_value.get_section()
     }
 
-    /// Set the section to which this function should belong
+    /// Set the section to which this function should belong. Placing rarely executed
+    /// functions in a section such as `.text.unlikely` keeps them away from hot code.
     pub fn set_section(self, section: Option<&str>) {
         self.fn_value.set_section(section)
     }
@@ -32,6 +32,8 @@ use std::fmt::{self, Display};
 use crate::comdat::Comdat;
 use crate::module::Linkage;
 use crate::values::traits::AsValueRef;
+#[llvm_versions(9.0..=latest)]
+use crate::values::FunctionValue;
 #[llvm_versions(8.0..=latest)]
 use crate::values::MetadataValue;
 use crate::values::{BasicValue, BasicValueEnum, PointerValue, Value};
@@ -227,6 +229,45 @@ impl<'ctx> GlobalValue<'ctx> {
         LLVMDeleteGlobal(self.as_value_ref())
     }
 
+    /// Gets the resolver of this indirect function, or `None` if this isn't an indirect
+    /// function or its resolver isn't a plain function.
+    #[llvm_versions(9.0..=latest)]
+    pub fn get_ifunc_resolver(self) -> Option<FunctionValue<'ctx>> {
+        use llvm_sys::core::{LLVMGetGlobalIFuncResolver, LLVMIsAFunction, LLVMIsAGlobalIFunc};
+
+        unsafe {
+            if LLVMIsAGlobalIFunc(self.as_value_ref()).is_null() {
+                return None;
+            }
+
+            let resolver = LLVMGetGlobalIFuncResolver(self.as_value_ref());
+
+            // The resolver may also be a constant expression, such as a bitcast
+            if resolver.is_null() || LLVMIsAFunction(resolver).is_null() {
+                return None;
+            }
+
+            FunctionValue::new(resolver)
+        }
+    }
+
+    /// Replaces the resolver of this indirect function, such as to late-bind a runtime
+    /// feature dispatch. Does nothing and returns `Err` if this isn't an indirect function.
+    #[llvm_versions(9.0..=latest)]
+    pub fn set_ifunc_resolver(self, resolver: FunctionValue<'ctx>) -> Result<(), &'static str> {
+        use llvm_sys::core::{LLVMIsAGlobalIFunc, LLVMSetGlobalIFuncResolver};
+
+        unsafe {
+            if LLVMIsAGlobalIFunc(self.as_value_ref()).is_null() {
+                return Err("The global value is not an indirect function.");
+            }
+
+            LLVMSetGlobalIFuncResolver(self.as_value_ref(), resolver.as_value_ref());
+        }
+
+        Ok(())
+    }
+
     pub fn as_pointer_value(self) -> PointerValue<'ctx> {
         unsafe { PointerValue::new(self.as_value_ref()) }
     }
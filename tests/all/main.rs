@@ -18,5 +18,6 @@ mod test_object_file;
 mod test_passes;
 mod test_targets;
 mod test_tari_example;
+mod test_testing;
 mod test_types;
 mod test_values;
@@ -1,7 +1,7 @@
 use inkwell::context::Context;
 use inkwell::values::BasicValue;
-use inkwell::values::CallableValue;
-use inkwell::{AddressSpace, AtomicOrdering, AtomicRMWBinOp, OptimizationLevel};
+use inkwell::values::{CallableValue, InstructionOpcode};
+use inkwell::{AddressSpace, AtomicOrdering, AtomicRMWBinOp, IntPredicate, OptimizationLevel};
 
 use std::convert::TryFrom;
 use std::ptr::null;
@@ -1448,3 +1448,407 @@ fn test_safe_struct_gep() {
     assert!(builder.build_struct_gep(struct_ptr, 1, "struct_gep").is_ok());
     assert!(builder.build_struct_gep(struct_ptr, 2, "struct_gep").is_err());
 }
+
+#[llvm_versions(9.0..=latest)]
+#[test]
+fn test_bit_manipulation_intrinsics() {
+    let context = Context::create();
+    let module = context.create_module("bits");
+    let builder = context.create_builder();
+    let i32_type = context.i32_type();
+    let i32_vec_type = i32_type.vec_type(4);
+    let fn_type = i32_type.fn_type(&[i32_type.into(), i32_vec_type.into()], false);
+    let fn_value = module.add_function("bits", fn_type, None);
+    let entry = context.append_basic_block(fn_value, "entry");
+    let i32_arg = fn_value.get_first_param().unwrap().into_int_value();
+    let vec_arg = fn_value.get_last_param().unwrap().into_vector_value();
+
+    // Not positioned in a function yet
+    assert!(builder.build_bswap(i32_arg, "bswap").is_err());
+
+    builder.position_at_end(entry);
+
+    let bswap = builder.build_bswap(i32_arg, "bswap").unwrap();
+    let bitreverse = builder.build_bitreverse(bswap, "bitreverse").unwrap();
+    let ctpop = builder.build_ctpop(bitreverse, "ctpop").unwrap();
+    let ctlz = builder.build_ctlz(ctpop, true, "ctlz").unwrap();
+    let cttz = builder.build_cttz(ctlz, false, "cttz").unwrap();
+    let fshl = builder.build_fshl(cttz, i32_arg, ctlz, "fshl").unwrap();
+    let fshr = builder.build_fshr(fshl, fshl, cttz, "fshr").unwrap();
+
+    builder.build_ctpop(vec_arg, "vec_ctpop").unwrap();
+    builder.build_return(Some(&fshr));
+
+    assert!(module.verify().is_ok());
+
+    let ir = module.print_to_string().to_string();
+
+    assert!(ir.contains("call i32 @llvm.bswap.i32(i32 %0)"));
+    assert!(ir.contains("call i32 @llvm.ctlz.i32(i32 %ctpop, i1 true)"));
+    assert!(ir.contains("call i32 @llvm.cttz.i32(i32 %ctlz, i1 false)"));
+    assert!(ir.contains("call <4 x i32> @llvm.ctpop.v4i32(<4 x i32> %1)"));
+    assert!(module.get_function("llvm.fshl.i32").is_some());
+    assert!(module.get_function("llvm.fshr.i32").is_some());
+}
+
+#[llvm_versions(9.0..=latest)]
+#[test]
+fn test_build_expect() {
+    let context = Context::create();
+    let module = context.create_module("expect");
+    let builder = context.create_builder();
+    let bool_type = context.bool_type();
+    let i64_type = context.i64_type();
+    let fn_type = i64_type.fn_type(&[bool_type.into(), i64_type.into()], false);
+    let fn_value = module.add_function("expect", fn_type, None);
+    let entry = context.append_basic_block(fn_value, "entry");
+    let then_block = context.append_basic_block(fn_value, "then");
+    let else_block = context.append_basic_block(fn_value, "else");
+    let cond = fn_value.get_first_param().unwrap().into_int_value();
+    let i64_arg = fn_value.get_last_param().unwrap().into_int_value();
+
+    builder.position_at_end(entry);
+
+    // The expected value must be a constant
+    assert!(builder.build_expect(i64_arg, i64_arg, "expect").is_err());
+
+    let expected = builder
+        .build_expect(i64_arg, i64_type.const_int(42, false), "expected")
+        .unwrap();
+    let likely = builder
+        .build_expect(cond, bool_type.const_all_ones(), "likely")
+        .unwrap();
+
+    builder.build_conditional_branch(likely, then_block, else_block);
+    builder.position_at_end(then_block);
+    builder.build_return(Some(&expected));
+    builder.position_at_end(else_block);
+    builder.build_return(Some(&i64_arg));
+
+    assert!(module.verify().is_ok());
+    assert!(module.get_function("llvm.expect.i1").is_some());
+    assert!(module.get_function("llvm.expect.i64").is_some());
+}
+
+#[llvm_versions(9.0..=latest)]
+#[test]
+fn test_build_assume() {
+    let context = Context::create();
+    let module = context.create_module("assume");
+    let builder = context.create_builder();
+    let i32_type = context.i32_type();
+    let f32_ptr_type = context.f32_type().ptr_type(AddressSpace::Generic);
+    let fn_type = context
+        .void_type()
+        .fn_type(&[i32_type.into(), f32_ptr_type.into()], false);
+    let fn_value = module.add_function("assume", fn_type, None);
+    let entry = context.append_basic_block(fn_value, "entry");
+    let i32_arg = fn_value.get_first_param().unwrap().into_int_value();
+    let ptr = fn_value.get_last_param().unwrap().into_pointer_value();
+
+    builder.position_at_end(entry);
+
+    // Only i1 conditions may be assumed
+    assert!(builder.build_assume(i32_arg).is_err());
+    assert!(builder.build_assume_aligned(ptr, 3).is_err());
+
+    let cond = builder.build_int_compare(IntPredicate::SGT, i32_arg, i32_type.const_zero(), "positive");
+    let assume = builder.build_assume(cond).unwrap();
+
+    assert_eq!(assume.get_opcode(), InstructionOpcode::Call);
+
+    builder.build_assume_aligned(ptr, 64).unwrap();
+    builder.build_assume_nonnull(ptr).unwrap();
+    builder.build_return(None);
+
+    assert!(module.verify().is_ok());
+
+    let ir = module.print_to_string().to_string();
+
+    assert!(ir.contains("and i64 %2, 63"));
+    assert_eq!(ir.matches("call void @llvm.assume(i1").count(), 3);
+}
+
+#[llvm_versions(9.0..=latest)]
+#[test]
+fn test_build_lifetime_markers() {
+    let context = Context::create();
+    let module = context.create_module("lifetime");
+    let builder = context.create_builder();
+    let i8_type = context.i8_type();
+    let i64_type = context.i64_type();
+    let fn_type = context.void_type().fn_type(&[], false);
+    let fn_value = module.add_function("lifetime", fn_type, None);
+    let entry = context.append_basic_block(fn_value, "entry");
+
+    builder.position_at_end(entry);
+
+    let buffer = builder.build_alloca(i8_type.array_type(32), "buffer");
+    let counter = builder.build_alloca(i64_type, "counter");
+    let buffer_i8 = builder
+        .build_bitcast(buffer, i8_type.ptr_type(AddressSpace::Generic), "buffer_i8")
+        .into_pointer_value();
+
+    let start = builder.build_lifetime_start(buffer_i8, 32).unwrap();
+
+    assert_eq!(start.get_opcode(), InstructionOpcode::Call);
+
+    builder.build_lifetime_end(buffer_i8, 32).unwrap();
+    builder.build_lifetime_start(counter, u64::MAX).unwrap();
+    builder.build_store(counter, i64_type.const_zero());
+    builder.build_lifetime_end(counter, u64::MAX).unwrap();
+    builder.build_return(None);
+
+    assert!(module.verify().is_ok());
+
+    let ir = module.print_to_string().to_string();
+
+    assert!(ir.contains("call void @llvm.lifetime.start.p0i8(i64 32, i8* %buffer_i8)"));
+    assert!(ir.contains("call void @llvm.lifetime.end.p0i64(i64 -1, i64* %counter)"));
+}
+
+#[llvm_versions(9.0..=latest)]
+#[test]
+fn test_build_prefetch() {
+    let context = Context::create();
+    let module = context.create_module("prefetch");
+    let builder = context.create_builder();
+    let i8_ptr_type = context.i8_type().ptr_type(AddressSpace::Generic);
+    let fn_type = context.void_type().fn_type(&[i8_ptr_type.into()], false);
+    let fn_value = module.add_function("prefetch", fn_type, None);
+    let entry = context.append_basic_block(fn_value, "entry");
+    let ptr = fn_value.get_first_param().unwrap().into_pointer_value();
+
+    builder.position_at_end(entry);
+
+    assert!(builder.build_prefetch(ptr, false, 4, true).is_err());
+
+    builder.build_prefetch(ptr, false, 3, true).unwrap();
+    builder.build_prefetch(ptr, true, 0, false).unwrap();
+    builder.build_return(None);
+
+    assert!(module.verify().is_ok());
+
+    let ir = module.print_to_string().to_string();
+
+    assert!(ir.contains("i32 0, i32 3, i32 1)"));
+    assert!(ir.contains("i32 1, i32 0, i32 0)"));
+}
+
+#[llvm_versions(12.0..=latest)]
+#[test]
+fn test_build_traps() {
+    let context = Context::create();
+    let module = context.create_module("traps");
+    let builder = context.create_builder();
+    let fn_type = context.void_type().fn_type(&[], false);
+    let fn_value = module.add_function("traps", fn_type, None);
+    let entry = context.append_basic_block(fn_value, "entry");
+
+    assert!(builder.build_trap().is_err());
+
+    builder.position_at_end(entry);
+    builder.build_debugtrap().unwrap();
+    builder.build_ubsantrap(7).unwrap();
+
+    let trap = builder.build_trap().unwrap();
+
+    assert_eq!(trap.get_opcode(), InstructionOpcode::Call);
+
+    builder.build_unreachable();
+
+    assert!(module.verify().is_ok());
+
+    let ir = module.print_to_string().to_string();
+
+    assert!(ir.contains("call void @llvm.debugtrap()"));
+    assert!(ir.contains("call void @llvm.ubsantrap(i8 7)"));
+    assert!(ir.contains("call void @llvm.trap()"));
+}
+
+#[llvm_versions(9.0..=latest)]
+#[test]
+fn test_build_return_and_frame_address() {
+    let context = Context::create();
+    let module = context.create_module("frames");
+    let builder = context.create_builder();
+    let i8_ptr_type = context.i8_type().ptr_type(AddressSpace::Generic);
+    let fn_type = i8_ptr_type.fn_type(&[], false);
+    let fn_value = module.add_function("frames", fn_type, None);
+    let entry = context.append_basic_block(fn_value, "entry");
+
+    builder.position_at_end(entry);
+
+    let frame_address = builder.build_frame_address(0, "frame_addr").unwrap();
+    let return_address = builder.build_return_address(1, "ret_addr").unwrap();
+
+    assert_eq!(frame_address.get_type(), i8_ptr_type);
+    assert_eq!(return_address.get_type(), i8_ptr_type);
+
+    builder.build_return(Some(&return_address));
+
+    assert!(module.verify().is_ok());
+
+    let ir = module.print_to_string().to_string();
+
+    assert!(ir.contains("call i8* @llvm.frameaddress.p0i8(i32 0)"));
+    assert!(ir.contains("call i8* @llvm.returnaddress(i32 1)"));
+}
+
+#[llvm_versions(9.0..=latest)]
+#[test]
+fn test_build_stack_save_restore() {
+    let context = Context::create();
+    let module = context.create_module("vla");
+    let builder = context.create_builder();
+    let i32_type = context.i32_type();
+    let fn_type = context.void_type().fn_type(&[i32_type.into()], false);
+    let fn_value = module.add_function("vla", fn_type, None);
+    let entry = context.append_basic_block(fn_value, "entry");
+    let len = fn_value.get_first_param().unwrap().into_int_value();
+
+    builder.position_at_end(entry);
+
+    let stack = builder.build_stack_save("stack").unwrap();
+    let array = builder.build_array_alloca(i32_type, len, "array");
+
+    builder.build_store(array, i32_type.const_zero());
+
+    let restore = builder.build_stack_restore(stack).unwrap();
+
+    assert_eq!(restore.get_opcode(), InstructionOpcode::Call);
+
+    builder.build_return(None);
+
+    assert!(module.verify().is_ok());
+
+    let ir = module.print_to_string().to_string();
+
+    assert!(ir.contains("%stack = call i8* @llvm.stacksave()"));
+    assert!(ir.contains("call void @llvm.stackrestore(i8* %stack)"));
+}
+
+#[llvm_versions(9.0..=latest)]
+#[test]
+fn test_build_register_intrinsics() {
+    let context = Context::create();
+    let module = context.create_module("registers");
+    let builder = context.create_builder();
+    let i64_type = context.i64_type();
+    let fn_type = i64_type.fn_type(&[], false);
+    let fn_value = module.add_function("registers", fn_type, None);
+    let entry = context.append_basic_block(fn_value, "entry");
+
+    builder.position_at_end(entry);
+
+    let thread_pointer = builder.build_thread_pointer("tp").unwrap();
+    let sp = builder.build_read_register("sp", i64_type, "sp").unwrap();
+
+    assert_eq!(
+        thread_pointer.get_type(),
+        context.i8_type().ptr_type(AddressSpace::Generic)
+    );
+    assert_eq!(sp.get_type(), i64_type);
+
+    builder.build_write_register("sp", sp).unwrap();
+    builder.build_return(Some(&sp));
+
+    assert!(module.verify().is_ok());
+
+    let ir = module.print_to_string().to_string();
+
+    assert!(ir.contains("call i8* @llvm.thread.pointer()"));
+    assert!(ir.contains("%sp = call i64 @llvm.read_register.i64(metadata !0)"));
+    assert!(ir.contains("call void @llvm.write_register.i64(metadata !0, i64 %sp)"));
+    assert!(ir.contains("!0 = !{!\"sp\"}"));
+}
+
+#[llvm_versions(9.0..=latest)]
+#[test]
+fn test_build_read_cycle_counter() {
+    let context = Context::create();
+    let module = context.create_module("profile");
+    let builder = context.create_builder();
+    let i64_type = context.i64_type();
+    let fn_type = i64_type.fn_type(&[], false);
+    let fn_value = module.add_function("elapsed", fn_type, None);
+    let entry = context.append_basic_block(fn_value, "entry");
+
+    builder.position_at_end(entry);
+
+    let start = builder.build_read_cycle_counter("start").unwrap();
+    let end = builder.build_read_cycle_counter("end").unwrap();
+
+    assert_eq!(start.get_type(), i64_type);
+
+    let elapsed = builder.build_int_sub(end, start, "elapsed");
+
+    builder.build_return(Some(&elapsed));
+
+    assert!(module.verify().is_ok());
+    assert!(module.get_function("llvm.readcyclecounter").is_some());
+}
+
+#[llvm_versions(9.0..=latest)]
+#[test]
+fn test_build_instrprof_increment() {
+    let context = Context::create();
+    let module = context.create_module("coverage");
+    let builder = context.create_builder();
+    let fn_type = context.void_type().fn_type(&[], false);
+    let function = module.add_function("covered", fn_type, None);
+    let entry = context.append_basic_block(function, "entry");
+    let other = context.append_basic_block(function, "other");
+
+    builder.position_at_end(entry);
+
+    assert_eq!(
+        builder.build_instrprof_increment(function, 0x1234, 2, 2),
+        Err("The counter index must be less than the number of counters.")
+    );
+    assert!(builder.build_instrprof_increment(function, 0x1234, 2, 0).is_ok());
+
+    builder.build_unconditional_branch(other);
+    builder.position_at_end(other);
+
+    assert!(builder.build_instrprof_increment(function, 0x1234, 2, 1).is_ok());
+
+    builder.build_return(None);
+
+    assert!(module.verify().is_ok());
+
+    let ir = module.print_to_string().to_string();
+
+    assert_eq!(
+        ir.matches("@__profn_covered = private constant [7 x i8] c\"covered\"")
+            .count(),
+        1
+    );
+    assert!(ir.contains(
+        "call void @llvm.instrprof.increment(i8* getelementptr inbounds ([7 x i8], [7 x i8]* @__profn_covered, i32 0, i32 0), i64 4660, i32 2, i32 1)"
+    ));
+}
+
+#[test]
+fn test_build_address_space_cast_to() {
+    let context = Context::create();
+    let module = context.create_module("ascast");
+    let builder = context.create_builder();
+    let i32_type = context.i32_type();
+    let fn_type = context
+        .void_type()
+        .fn_type(&[i32_type.ptr_type(AddressSpace::NVPTX_SHARED).into()], false);
+    let fn_value = module.add_function("ascast", fn_type, None);
+    let shared_ptr = fn_value.get_first_param().unwrap().into_pointer_value();
+
+    builder.position_at_end(context.append_basic_block(fn_value, "entry"));
+
+    let generic_ptr = builder.build_address_space_cast_to(shared_ptr, AddressSpace::NVPTX_GENERIC, "generic_ptr");
+
+    builder.build_store(generic_ptr, i32_type.const_zero());
+    builder.build_return(None);
+
+    assert_eq!(generic_ptr.get_type().get_address_space(), AddressSpace::Generic);
+    assert_eq!(generic_ptr.get_type().get_element_type().into_int_type(), i32_type);
+    assert!(module.verify().is_ok());
+}
@@ -569,3 +569,240 @@ fn test_array_type() {
 
     dibuilder.create_array_type(di_type, 160, 64, &[(0..20), (-1..30), (20..55)]);
 }
+
+#[test]
+fn test_enumeration_and_union_types() {
+    let context = Context::create();
+    let module = context.create_module("bin");
+
+    let (dibuilder, compile_unit) = module.create_debug_info_builder(
+        true,
+        DWARFSourceLanguage::C,
+        "source_file",
+        ".",
+        "my llvm compiler frontend",
+        false,
+        "",
+        0,
+        "",
+        DWARFEmissionKind::Full,
+        0,
+        false,
+        false,
+        #[cfg(any(
+            feature = "llvm11-0",
+            feature = "llvm12-0",
+            feature = "llvm13-0",
+            feature = "llvm14-0"
+        ))]
+        "",
+        #[cfg(any(
+            feature = "llvm11-0",
+            feature = "llvm12-0",
+            feature = "llvm13-0",
+            feature = "llvm14-0"
+        ))]
+        "",
+    );
+
+    let file = compile_unit.get_file();
+    let scope = compile_unit.as_debug_info_scope();
+    let tag_type = dibuilder
+        .create_basic_type("u8", 8_u64, 0x08, DIFlags::ZERO)
+        .unwrap()
+        .as_type();
+    let float_type = dibuilder
+        .create_basic_type("f32", 32_u64, 0x04, DIFlags::ZERO)
+        .unwrap()
+        .as_type();
+
+    let enumerators = [
+        dibuilder.create_enumerator("Int", 0, true),
+        dibuilder.create_enumerator("Float", 1, true),
+    ];
+    dibuilder.create_enumeration_type(scope, "Tag", file, 1, 8, 8, &enumerators, Some(tag_type));
+
+    let int_member = dibuilder.create_member_type(scope, "int", file, 5, 8, 8, 0, DIFlags::ZERO, tag_type);
+    let float_member = dibuilder.create_member_type(scope, "float", file, 6, 32, 32, 0, DIFlags::ZERO, float_type);
+    let payload = dibuilder.create_union_type(
+        scope,
+        "Payload",
+        file,
+        4,
+        32,
+        32,
+        DIFlags::ZERO,
+        &[int_member.as_type(), float_member.as_type()],
+        0,
+        "Payload",
+    );
+
+    let gv = module.add_global(context.i32_type(), None, "payload");
+    let gv_debug = dibuilder.create_global_variable_expression(
+        scope,
+        "payload",
+        "",
+        file,
+        8,
+        payload.as_type(),
+        false,
+        None,
+        None,
+        32,
+    );
+
+    gv.set_initializer(&context.i32_type().const_zero());
+    gv.set_metadata(gv_debug.as_metadata_value(&context), 0);
+
+    dibuilder.finalize();
+
+    assert!(module.verify().is_ok());
+
+    let ir = module.print_to_string().to_string();
+
+    assert!(ir.contains("!DICompositeType(tag: DW_TAG_enumeration_type, name: \"Tag\", file: !3, line: 1, baseType: "));
+    assert!(ir.contains("!DIEnumerator(name: \"Int\", value: 0, isUnsigned: true)"));
+    assert!(ir.contains("!DIEnumerator(name: \"Float\", value: 1, isUnsigned: true)"));
+    assert!(ir.contains("!DICompositeType(tag: DW_TAG_union_type, name: \"Payload\""));
+    assert!(ir.contains("!DIDerivedType(tag: DW_TAG_member, name: \"float\""));
+}
+
+#[llvm_versions(9.0..=latest)]
+#[test]
+fn test_inlined_at_locations() {
+    let context = Context::create();
+    let module = context.create_module("bin");
+    let builder = context.create_builder();
+
+    let (dibuilder, compile_unit) = module.create_debug_info_builder(
+        true,
+        DWARFSourceLanguage::C,
+        "source_file",
+        ".",
+        "my llvm compiler frontend",
+        false,
+        "",
+        0,
+        "",
+        DWARFEmissionKind::Full,
+        0,
+        false,
+        false,
+        #[cfg(any(
+            feature = "llvm11-0",
+            feature = "llvm12-0",
+            feature = "llvm13-0",
+            feature = "llvm14-0"
+        ))]
+        "",
+        #[cfg(any(
+            feature = "llvm11-0",
+            feature = "llvm12-0",
+            feature = "llvm13-0",
+            feature = "llvm14-0"
+        ))]
+        "",
+    );
+
+    let file = compile_unit.get_file();
+    let subroutine_type = dibuilder.create_subroutine_type(file, None, &[], DIFlags::PUBLIC);
+    let create_function = |name, line_no| {
+        dibuilder.create_function(
+            compile_unit.as_debug_info_scope(),
+            name,
+            None,
+            file,
+            line_no,
+            subroutine_type,
+            false,
+            true,
+            line_no,
+            DIFlags::PUBLIC,
+            false,
+        )
+    };
+    let main_scope = create_function("main", 1);
+    let helper_scope = create_function("helper", 10);
+
+    let fn_type = context.void_type().fn_type(&[], false);
+    let fn_val = module.add_function("main", fn_type, None);
+
+    fn_val.set_subprogram(main_scope);
+    builder.position_at_end(context.append_basic_block(fn_val, "entry"));
+
+    let call_site = dibuilder.create_debug_location(&context, 3, 5, main_scope.as_debug_info_scope(), None);
+    let inlined = dibuilder.create_debug_location(&context, 11, 7, helper_scope.as_debug_info_scope(), Some(call_site));
+
+    assert!(call_site.get_inlined_at().is_none());
+    assert_eq!(inlined.get_inlined_at(), Some(call_site));
+    assert_eq!(inlined.get_inlined_at().unwrap().get_line(), 3);
+
+    builder.set_current_debug_location(&context, inlined);
+    builder.build_alloca(context.i32_type(), "inlined_local");
+
+    let location = builder.get_current_debug_location().unwrap();
+
+    assert_eq!(location.get_line(), 11);
+    assert_eq!(location.get_inlined_at(), Some(call_site));
+
+    builder.set_current_debug_location(&context, call_site);
+    builder.build_return(None);
+
+    dibuilder.finalize();
+
+    assert!(module.verify().is_ok());
+
+    let ir = module.print_to_string().to_string();
+
+    assert!(ir
+        .lines()
+        .any(|line| line.contains("!DILocation(line: 11, column: 7,") && line.contains("inlinedAt: ")));
+}
+
+#[test]
+fn test_compile_unit_parameters() {
+    let context = Context::create();
+    let module = context.create_module("bin");
+
+    let (dibuilder, _) = module.create_debug_info_builder(
+        true,
+        DWARFSourceLanguage::Rust,
+        "lib.rs",
+        "/src",
+        "mylang 1.2.3",
+        true,
+        "-O2 -g",
+        2,
+        "lib.dwo",
+        DWARFEmissionKind::LineTablesOnly,
+        42,
+        false,
+        true,
+        #[cfg(any(
+            feature = "llvm11-0",
+            feature = "llvm12-0",
+            feature = "llvm13-0",
+            feature = "llvm14-0"
+        ))]
+        "/sysroot",
+        #[cfg(any(
+            feature = "llvm11-0",
+            feature = "llvm12-0",
+            feature = "llvm13-0",
+            feature = "llvm14-0"
+        ))]
+        "MySDK.sdk",
+    );
+
+    dibuilder.finalize();
+
+    let ir = module.print_to_string().to_string();
+
+    assert!(ir.contains("!DICompileUnit(language: DW_LANG_Rust, file: "));
+    assert!(ir.contains("!DIFile(filename: \"lib.rs\", directory: \"/src\")"));
+    assert!(ir.contains(
+        "producer: \"mylang 1.2.3\", isOptimized: true, flags: \"-O2 -g\", runtimeVersion: 2, \
+         splitDebugFilename: \"lib.dwo\", emissionKind: LineTablesOnly, dwoId: 42, splitDebugInlining: false, \
+         debugInfoForProfiling: true"
+    ));
+}
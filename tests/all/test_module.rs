@@ -1,9 +1,10 @@
+use inkwell::attributes::AttributeLoc;
 use inkwell::context::Context;
 use inkwell::memory_buffer::MemoryBuffer;
-use inkwell::module::Module;
-use inkwell::targets::{Target, TargetTriple};
+use inkwell::module::{DuplicateDefinitions, LaunchBounds, Linkage, Module};
+use inkwell::targets::{CodeModel, FileType, InitializationConfig, RelocMode, Target, TargetTriple};
 use inkwell::values::AnyValue;
-use inkwell::OptimizationLevel;
+use inkwell::{AddressSpace, OptimizationLevel};
 
 use std::env::temp_dir;
 use std::fs::{remove_file, File};
@@ -255,6 +256,61 @@ fn test_parse_from_buffer() {
     assert_eq!(module4_result.unwrap().get_context(), context);
 }
 
+#[test]
+fn test_parse_from_buffer_lazily() {
+    let context = Context::create();
+    let garbage_buffer = MemoryBuffer::create_from_memory_range_copy(b"garbage ir data", "my_ir");
+
+    assert!(Module::parse_bitcode_from_buffer_lazily(garbage_buffer, &context).is_err());
+
+    let library = context.create_module("library");
+    let builder = context.create_builder();
+    let fn_type = context.void_type().fn_type(&[], false);
+
+    for name in &["used", "unused"] {
+        let function = library.add_function(name, fn_type, None);
+
+        builder.position_at_end(context.append_basic_block(function, "entry"));
+        builder.build_return(None);
+    }
+
+    let bitcode = library.write_bitcode_to_memory();
+    let lazy_library = Module::parse_bitcode_from_buffer_lazily(bitcode, &context).unwrap();
+    let used = lazy_library.get_function("used").unwrap();
+    let unused = lazy_library.get_function("unused").unwrap();
+
+    assert!(used.is_materializable());
+    assert!(unused.is_materializable());
+    assert!(!used.as_global_value().is_declaration());
+    assert_eq!(used.count_basic_blocks(), 0);
+
+    assert!(lazy_library.materialize_function(used).is_ok());
+    assert!(!used.is_materializable());
+    assert_eq!(used.count_basic_blocks(), 1);
+    assert!(unused.is_materializable());
+
+    // Materializing twice is a no-op
+    assert!(lazy_library.materialize_function(used).is_ok());
+    assert_eq!(
+        library.materialize_function(unused),
+        Err("The function does not belong to this module.")
+    );
+
+    let main = context.create_module("main");
+    let declaration = main.add_function("unused", fn_type, None);
+
+    assert!(!declaration.is_materializable());
+
+    let lazy_library = Module::parse_bitcode_from_buffer_lazily(library.write_bitcode_to_memory(), &context).unwrap();
+
+    assert!(main.link_in_module(lazy_library).is_ok());
+
+    let linked = main.get_function("unused").unwrap();
+
+    assert_eq!(linked.count_basic_blocks(), 1);
+    assert!(main.verify().is_ok());
+}
+
 #[test]
 fn test_parse_from_path() {
     let context = Context::create();
@@ -438,6 +494,119 @@ fn test_linking_modules() {
     assert_eq!(execution_engine2.get_function_value("f4"), Ok(fn_val4));
 }
 
+#[test]
+fn test_linking_modules_with_duplicates() {
+    let context = Context::create();
+    let builder = context.create_builder();
+    let i32_type = context.i32_type();
+    let fn_type = i32_type.fn_type(&[], false);
+
+    let build_module = |name: &str, value: u64| {
+        let module = context.create_module(name);
+        let foo = module.add_function("foo", fn_type, None);
+
+        builder.position_at_end(context.append_basic_block(foo, "entry"));
+        builder.build_return(Some(&i32_type.const_int(value, false)));
+
+        let weak = module.add_function("weak", fn_type, Some(Linkage::WeakAny));
+
+        builder.position_at_end(context.append_basic_block(weak, "entry"));
+        builder.build_return(Some(&i32_type.const_int(value, false)));
+
+        let global = module.add_global(i32_type, None, "counter");
+
+        global.set_initializer(&i32_type.const_int(value, false));
+
+        // Neither local nor weak_odr definitions conflict
+        let local = module.add_function("local", fn_type, Some(Linkage::Internal));
+
+        builder.position_at_end(context.append_basic_block(local, "entry"));
+        builder.build_return(Some(&i32_type.const_int(value, false)));
+
+        let shared = module.add_global(i32_type, None, "shared");
+
+        shared.set_initializer(&i32_type.const_int(value, false));
+        shared.set_linkage(Linkage::WeakODR);
+
+        // Uses foo, so that overriding it has to keep the call intact
+        let caller = module.add_function(&format!("call_from_{}", name), fn_type, None);
+
+        builder.position_at_end(context.append_basic_block(caller, "entry"));
+
+        let call = builder
+            .build_call(foo, &[], "call")
+            .try_as_basic_value()
+            .left()
+            .unwrap();
+
+        builder.build_return(Some(&call));
+
+        module
+    };
+
+    let mut conflicts = Vec::new();
+    let a = build_module("a", 1);
+    let result = a.link_in_module_with_duplicates(build_module("b", 2), DuplicateDefinitions::Error, |name| {
+        conflicts.push(name.to_str().unwrap().to_owned())
+    });
+
+    assert_eq!(
+        result.unwrap_err().to_str(),
+        Ok("Linking globals named 'foo': symbol multiply defined!")
+    );
+    assert_eq!(conflicts, ["foo", "counter"]);
+
+    fn returned_value(module: &Module, name: &str) -> Option<u64> {
+        let function = module.get_function(name).unwrap();
+        let ret = function.get_first_basic_block().unwrap().get_terminator().unwrap();
+
+        ret.get_operand(0)
+            .unwrap()
+            .left()
+            .unwrap()
+            .into_int_value()
+            .get_zero_extended_constant()
+    }
+
+    let a = build_module("a", 1);
+
+    assert!(a
+        .link_in_module_with_duplicates(build_module("b", 2), DuplicateDefinitions::OverrideFromSource, |_| ())
+        .is_ok());
+    assert!(a.verify().is_ok());
+    assert_eq!(returned_value(&a, "foo"), Some(2));
+    assert_eq!(returned_value(&a, "weak"), Some(1));
+    assert_eq!(returned_value(&a, "local"), Some(1));
+    assert!(a.get_function("call_from_a").is_some());
+    assert!(a.get_function("call_from_b").is_some());
+    assert_eq!(
+        a.get_global("counter")
+            .unwrap()
+            .get_initializer()
+            .unwrap()
+            .into_int_value()
+            .get_zero_extended_constant(),
+        Some(2)
+    );
+
+    let a = build_module("a", 1);
+
+    assert!(a
+        .link_in_module_with_duplicates(build_module("b", 2), DuplicateDefinitions::KeepDestination, |_| ())
+        .is_ok());
+    assert!(a.verify().is_ok());
+    assert_eq!(returned_value(&a, "foo"), Some(1));
+    assert_eq!(
+        a.get_global("counter")
+            .unwrap()
+            .get_initializer()
+            .unwrap()
+            .into_int_value()
+            .get_zero_extended_constant(),
+        Some(1)
+    );
+}
+
 #[test]
 fn test_metadata_flags() {
     #[cfg(not(any(feature = "llvm4-0", feature = "llvm5-0", feature = "llvm6-0")))]
@@ -511,3 +680,287 @@ fn test_double_ee_from_same_module() {
 
     assert!(module.create_interpreter_execution_engine().is_err());
 }
+
+#[test]
+fn test_global_constructors_and_destructors() {
+    let context = Context::create();
+    let module = context.create_module("my_module");
+    let builder = context.create_builder();
+    let void_type = context.void_type();
+    let fn_type = void_type.fn_type(&[], false);
+    let init = module.add_function("init", fn_type, None);
+    let init2 = module.add_function("init2", fn_type, None);
+    let fini = module.add_function("fini", fn_type, None);
+    let bad = module.add_function("bad", context.i32_type().fn_type(&[], false), None);
+    let data = module.add_global(context.i32_type(), None, "data");
+
+    data.set_initializer(&context.i32_type().const_zero());
+
+    for function in [init, init2, fini] {
+        builder.position_at_end(context.append_basic_block(function, "entry"));
+        builder.build_return(None);
+    }
+
+    assert!(module.add_global_constructor(65535, bad, None).is_err());
+    assert!(module.get_global("llvm.global_ctors").is_none());
+
+    module.add_global_constructor(65535, init, None).unwrap();
+    module.add_global_constructor(100, init2, Some(data)).unwrap();
+    module.add_global_destructor(65535, fini, None).unwrap();
+
+    assert!(module.verify().is_ok());
+
+    let ctors = module.get_global("llvm.global_ctors").unwrap();
+
+    assert_eq!(ctors.get_linkage(), Linkage::Appending);
+    assert_eq!(ctors.get_initializer().unwrap().into_array_value().get_type().len(), 2);
+    assert_eq!(
+        module
+            .get_global("llvm.global_dtors")
+            .unwrap()
+            .get_initializer()
+            .unwrap()
+            .into_array_value()
+            .get_type()
+            .len(),
+        1
+    );
+
+    let ir = module.print_to_string().to_string();
+
+    assert!(ir.contains(
+        "@llvm.global_ctors = appending global [2 x { i32, void ()*, i8* }] [{ i32, void ()*, i8* } { i32 65535, void ()* @init, i8* null }, { i32, void ()*, i8* } { i32 100, void ()* @init2, i8* bitcast (i32* @data to i8*) }]"
+    ));
+}
+
+#[test]
+fn test_append_to_used() {
+    let context = Context::create();
+    let module = context.create_module("my_module");
+    let i32_type = context.i32_type();
+    let global = module.add_global(i32_type, None, "from_asm");
+    let global2 = module.add_global(i32_type, Some(AddressSpace::Global), "from_tool");
+    let fn_value = module.add_function("from_linker_script", context.void_type().fn_type(&[], false), None);
+
+    global.set_initializer(&i32_type.const_zero());
+    global2.set_initializer(&i32_type.const_zero());
+
+    module.append_to_used(&[global]).unwrap();
+    module.append_to_used(&[global, global2, global2]).unwrap();
+    module.append_to_compiler_used(&[fn_value.as_global_value()]).unwrap();
+
+    assert!(module.verify().is_ok());
+
+    let used = module.get_global("llvm.used").unwrap();
+    let compiler_used = module.get_global("llvm.compiler.used").unwrap();
+
+    assert_eq!(used.get_initializer().unwrap().into_array_value().get_type().len(), 2);
+    assert_eq!(used.get_section().unwrap().to_str(), Ok("llvm.metadata"));
+    assert_eq!(
+        compiler_used
+            .get_initializer()
+            .unwrap()
+            .into_array_value()
+            .get_type()
+            .len(),
+        1
+    );
+    assert_eq!(compiler_used.get_linkage(), Linkage::Appending);
+
+    let ir = module.print_to_string().to_string();
+
+    assert!(ir.contains("i8* bitcast (i32* @from_asm to i8*), i8* addrspacecast"));
+    assert_eq!(ir.matches("@from_tool").count(), 2);
+}
+
+#[test]
+fn test_append_to_global_array() {
+    let context = Context::create();
+    let module = context.create_module("my_module");
+    let i8_type = context.i8_type();
+    let i32_type = context.i32_type();
+    let one = i32_type.const_int(1, false);
+    let two = i32_type.const_int(2, false);
+
+    // Element types must match
+    assert!(module
+        .append_to_global_array("registry", i8_type, &[one.into()])
+        .is_err());
+
+    let registry = module
+        .append_to_global_array("registry", i32_type, &[one.into()])
+        .unwrap();
+
+    registry.set_section(Some("registrations"));
+
+    let registry = module
+        .append_to_global_array("registry", i32_type, &[two.into(), one.into()])
+        .unwrap();
+    let array = registry.get_initializer().unwrap().into_array_value();
+
+    assert_eq!(registry.get_name().to_str(), Ok("registry"));
+    assert_eq!(registry.get_linkage(), Linkage::Appending);
+    assert_eq!(registry.get_section().unwrap().to_str(), Ok("registrations"));
+    assert_eq!(array.get_type().len(), 3);
+    assert_eq!(array.get_type().get_element_type(), i32_type.into());
+    assert!(module.append_to_global_array("registry", i8_type, &[]).is_err());
+
+    // Data arrays and zero initialized arrays are read back as well
+    let bytes = module.add_global(i8_type.array_type(2), None, "bytes");
+
+    bytes.set_linkage(Linkage::Appending);
+    bytes.set_initializer(&i8_type.const_array(&[i8_type.const_int(7, false), i8_type.const_int(8, false)]));
+
+    let zeros = module.add_global(i8_type.array_type(2), None, "zeros");
+
+    zeros.set_linkage(Linkage::Appending);
+    zeros.set_initializer(&i8_type.array_type(2).const_zero());
+
+    module
+        .append_to_global_array("bytes", i8_type, &[i8_type.const_int(9, false).into()])
+        .unwrap();
+    module
+        .append_to_global_array("zeros", i8_type, &[i8_type.const_int(9, false).into()])
+        .unwrap();
+
+    // Non appending globals are left alone
+    let internal = module.add_global(i32_type, None, "internal");
+
+    internal.set_initializer(&one);
+
+    assert!(module
+        .append_to_global_array("internal", i32_type, &[one.into()])
+        .is_err());
+    assert!(module.verify().is_ok());
+
+    let ir = module.print_to_string().to_string();
+
+    assert!(ir.contains("@bytes = appending global [3 x i8] c\"\\07\\08\\09\""));
+    assert!(ir.contains("@zeros = appending global [3 x i8] c\"\\00\\00\\09\""));
+}
+
+#[test]
+fn test_gpu_kernels() {
+    let context = Context::create();
+    let module = context.create_module("kernels");
+    let builder = context.create_builder();
+    let void_type = context.void_type();
+    let fn_type = void_type.fn_type(&[context.i32_type().ptr_type(AddressSpace::Global).into()], false);
+    let kernel = module.add_function("kernel", fn_type, None);
+    let launch_bounds = LaunchBounds {
+        max_threads_per_block: 128,
+        min_blocks_per_multiprocessor: Some(4),
+    };
+
+    builder.position_at_end(context.append_basic_block(kernel, "entry"));
+    builder.build_return(None);
+
+    module.add_nvptx_kernel(kernel, Some(launch_bounds)).unwrap();
+
+    assert_eq!(module.get_global_metadata_size("nvvm.annotations"), 3);
+
+    let ir = module.print_to_string().to_string();
+
+    assert!(ir.contains("!nvvm.annotations = !{!0, !1, !2}"));
+    assert!(ir.contains("@kernel, !\"kernel\", i32 1}"));
+    assert!(ir.contains("@kernel, !\"maxntidx\", i32 128}"));
+    assert!(ir.contains("@kernel, !\"minctasm\", i32 4}"));
+
+    Target::initialize_nvptx(&InitializationConfig::default());
+
+    let triple = TargetTriple::create("nvptx64-nvidia-cuda");
+    let machine = Target::from_triple(&triple)
+        .unwrap()
+        .create_target_machine(
+            &triple,
+            "sm_50",
+            "",
+            OptimizationLevel::Default,
+            RelocMode::Default,
+            CodeModel::Default,
+        )
+        .unwrap();
+
+    module.set_triple(&triple);
+
+    let buffer = machine.write_to_memory_buffer(&module, FileType::Assembly).unwrap();
+    let ptx = std::str::from_utf8(buffer.as_slice()).unwrap();
+
+    assert!(ptx.contains(".entry kernel"));
+    assert!(ptx.contains(".maxntid 128, 1, 1"));
+    assert!(ptx.contains(".minnctapersm 4"));
+
+    module.add_amdgpu_kernel(kernel, Some(launch_bounds)).unwrap();
+
+    assert_eq!(kernel.get_call_conventions(), 91);
+
+    let work_group_size = kernel.get_string_attribute(AttributeLoc::Function, "amdgpu-flat-work-group-size");
+    let waves_per_eu = kernel.get_string_attribute(AttributeLoc::Function, "amdgpu-waves-per-eu");
+
+    assert_eq!(work_group_size.unwrap().get_string_value().to_str(), Ok("1,128"));
+    assert_eq!(waves_per_eu.unwrap().get_string_value().to_str(), Ok("4"));
+
+    let other_module = context.create_module("other");
+    let other_kernel = other_module.add_function("kernel", fn_type, None);
+    let bad_kernel = module.add_function("bad_kernel", context.i32_type().fn_type(&[], false), None);
+
+    assert_eq!(
+        module.add_nvptx_kernel(other_kernel, None),
+        Err("The function does not belong to this module.")
+    );
+    assert_eq!(
+        module.add_amdgpu_kernel(bad_kernel, None),
+        Err("GPU kernels must return void.")
+    );
+}
+
+#[llvm_versions(9.0..=latest)]
+#[test]
+fn test_global_ifunc() {
+    let context = Context::create();
+    let module = context.create_module("ifunc");
+    let builder = context.create_builder();
+    let fn_type = context.i32_type().fn_type(&[], false);
+    let resolver_type = fn_type.ptr_type(AddressSpace::Generic).fn_type(&[], false);
+    let generic_impl = module.add_function("popcount_generic", fn_type, None);
+    let fast_impl = module.add_function("popcount_fast", fn_type, None);
+    let generic_resolver = module.add_function("resolve_generic", resolver_type, None);
+    let fast_resolver = module.add_function("resolve_fast", resolver_type, None);
+
+    for (resolver, implementation) in [(generic_resolver, generic_impl), (fast_resolver, fast_impl)] {
+        builder.position_at_end(context.append_basic_block(resolver, "entry"));
+        builder.build_return(Some(&implementation.as_global_value().as_pointer_value()));
+    }
+
+    assert!(module.get_global_ifunc("popcount").is_none());
+
+    let ifunc = module.add_global_ifunc("popcount", fn_type, AddressSpace::Generic, generic_resolver);
+
+    assert_eq!(ifunc.get_name().to_str(), Ok("popcount"));
+    assert_eq!(module.get_global_ifunc("popcount"), Some(ifunc));
+    assert_eq!(ifunc.get_ifunc_resolver(), Some(generic_resolver));
+    assert!(module
+        .print_to_string()
+        .to_string()
+        .contains("@popcount = ifunc i32 (), i32 ()* ()* @resolve_generic"));
+    assert!(module.verify().is_ok());
+
+    assert!(ifunc.set_ifunc_resolver(fast_resolver).is_ok());
+    assert_eq!(ifunc.get_ifunc_resolver(), Some(fast_resolver));
+
+    ifunc.set_linkage(Linkage::Internal);
+
+    assert!(module
+        .print_to_string()
+        .to_string()
+        .contains("@popcount = internal ifunc i32 (), i32 ()* ()* @resolve_fast"));
+    assert!(module.verify().is_ok());
+
+    let global = module.add_global(context.i32_type(), None, "not_an_ifunc");
+
+    assert!(global.get_ifunc_resolver().is_none());
+    assert_eq!(
+        global.set_ifunc_resolver(fast_resolver),
+        Err("The global value is not an indirect function.")
+    );
+}
@@ -1,11 +1,15 @@
+#[llvm_versions(13.0..=latest)]
+use inkwell::attributes::{Attribute, AttributeLoc};
 use inkwell::context::Context;
 use inkwell::passes::{PassManager, PassManagerBuilder, PassRegistry};
 
 #[llvm_versions(13.0..=latest)]
-use inkwell::passes::PassBuilderOptions;
+use inkwell::passes::{PassBuilderOptions, Sanitizer};
 #[llvm_versions(13.0..=latest)]
-use inkwell::targets::{CodeModel, InitializationConfig, RelocMode, Target, TargetMachine};
+use inkwell::targets::{CodeModel, InitializationConfig, RelocMode, Target, TargetMachine, TargetTriple};
 use inkwell::OptimizationLevel;
+#[llvm_versions(13.0..=latest)]
+use inkwell::{AddressSpace, IntPredicate};
 
 #[test]
 fn test_init_all_passes_for_module() {
@@ -240,3 +244,246 @@ fn test_run_passes_invalid() {
     assert!(res.is_err());
     assert_eq!(res.unwrap_err().to_str().unwrap(), "unknown pass name 'invalid_pass'");
 }
+
+#[llvm_versions(13.0..=latest)]
+#[test]
+fn test_run_instrprof_pass() {
+    Target::initialize_native(&InitializationConfig::default()).unwrap();
+
+    let context = Context::create();
+    let builder = context.create_builder();
+    let triple = TargetMachine::get_default_triple();
+    let machine = Target::from_triple(&triple)
+        .unwrap()
+        .create_target_machine(
+            &triple,
+            "generic",
+            "",
+            OptimizationLevel::None,
+            RelocMode::Default,
+            CodeModel::Default,
+        )
+        .unwrap();
+    let fn_type = context.void_type().fn_type(&[], false);
+    let module = context.create_module("instrprof");
+    let function = module.add_function("covered", fn_type, None);
+
+    module.set_triple(&triple);
+    builder.position_at_end(context.append_basic_block(function, "entry"));
+    builder.build_instrprof_increment(function, 0x1234, 1, 0).unwrap();
+    builder.build_return(None);
+
+    module
+        .run_passes("instrprof", &machine, PassBuilderOptions::create())
+        .unwrap();
+
+    let ir = module.print_to_string().to_string();
+
+    assert!(ir.contains("@__profc_covered"));
+    assert!(!ir.contains("call void @llvm.instrprof.increment"));
+}
+
+#[llvm_versions(13.0..=latest)]
+#[test]
+fn test_run_passes_with_pgo_options() {
+    Target::initialize_native(&InitializationConfig::default()).unwrap();
+
+    let context = Context::create();
+    let builder = context.create_builder();
+    let triple = TargetMachine::get_default_triple();
+    let machine = Target::from_triple(&triple)
+        .unwrap()
+        .create_target_machine(
+            &triple,
+            "generic",
+            "",
+            OptimizationLevel::None,
+            RelocMode::Default,
+            CodeModel::Default,
+        )
+        .unwrap();
+    let i32_type = context.i32_type();
+    let fn_type = i32_type.fn_type(&[i32_type.into()], false);
+    let module = context.create_module("pgo");
+    let function = module.add_function("choose", fn_type, None);
+    let entry = context.append_basic_block(function, "entry");
+    let then_block = context.append_basic_block(function, "then");
+    let else_block = context.append_basic_block(function, "else");
+    let x = function.get_first_param().unwrap().into_int_value();
+
+    module.set_triple(&triple);
+    builder.position_at_end(entry);
+
+    let is_zero = builder.build_int_compare(IntPredicate::EQ, x, i32_type.const_zero(), "is_zero");
+
+    builder.build_conditional_branch(is_zero, then_block, else_block);
+    builder.position_at_end(then_block);
+    builder.build_return(Some(&i32_type.const_int(1, false)));
+    builder.position_at_end(else_block);
+    builder.build_return(Some(&x));
+
+    let pass_options = PassBuilderOptions::create();
+
+    pass_options.set_pgo_instr_gen(true);
+
+    module.run_passes("default<O0>", &machine, pass_options).unwrap();
+
+    let ir = module.print_to_string().to_string();
+
+    assert!(ir.contains("@__profc_choose"));
+    assert!(!ir.contains("call void @llvm.instrprof.increment"));
+}
+
+#[llvm_versions(13.0..=latest)]
+#[test]
+fn test_run_passes_with_sanitizers() {
+    Target::initialize_native(&InitializationConfig::default()).unwrap();
+
+    let context = Context::create();
+    let builder = context.create_builder();
+    let triple = TargetMachine::get_default_triple();
+    let machine = Target::from_triple(&triple)
+        .unwrap()
+        .create_target_machine(
+            &triple,
+            "generic",
+            "",
+            OptimizationLevel::None,
+            RelocMode::Default,
+            CodeModel::Default,
+        )
+        .unwrap();
+    let i32_type = context.i32_type();
+    let fn_type = i32_type.fn_type(&[i32_type.ptr_type(AddressSpace::Generic).into()], false);
+
+    let create_module = |name| {
+        let module = context.create_module(name);
+        let function = module.add_function("load", fn_type, None);
+        let ptr = function.get_first_param().unwrap().into_pointer_value();
+
+        builder.position_at_end(context.append_basic_block(function, "entry"));
+
+        let value = builder.build_load(ptr, "value");
+
+        builder.build_return(Some(&value));
+
+        module
+    };
+
+    // A pipeline which fails to parse leaves no sanitizer attributes behind
+    let module = create_module("asan");
+    let pass_options = PassBuilderOptions::create();
+
+    pass_options.enable_asan();
+
+    assert!(module.run_passes("not-a-pass", &machine, pass_options).is_err());
+    assert!(!module.print_to_string().to_string().contains("sanitize_address"));
+
+    let pass_options = PassBuilderOptions::create();
+
+    pass_options.enable_asan();
+
+    assert_eq!(pass_options.get_sanitizer(), Some(Sanitizer::Address));
+
+    module.run_passes("default<O0>", &machine, pass_options).unwrap();
+
+    let ir = module.print_to_string().to_string();
+
+    assert!(ir.contains("sanitize_address"));
+    assert!(ir.contains("@__asan_report_load4"));
+
+    let module = create_module("tsan");
+    let pass_options = PassBuilderOptions::create();
+
+    pass_options.enable_tsan();
+    module.run_passes("default<O0>", &machine, pass_options).unwrap();
+
+    assert!(module.print_to_string().to_string().contains("@__tsan_read4"));
+
+    let module = create_module("msan");
+    let pass_options = PassBuilderOptions::create();
+
+    pass_options.enable_msan();
+
+    let res = module.run_passes("default<O0>", &machine, pass_options);
+
+    assert_eq!(
+        res.unwrap_err().to_str(),
+        Ok("MemorySanitizer requires a Linux, FreeBSD or NetBSD target triple.")
+    );
+
+    let pass_options = PassBuilderOptions::create();
+
+    pass_options.enable_msan();
+    module.set_triple(&TargetTriple::create("x86_64-unknown-linux-gnu"));
+    module.run_passes("default<O0>", &machine, pass_options).unwrap();
+
+    assert!(module.print_to_string().to_string().contains("@__msan_param_tls"));
+}
+
+#[llvm_versions(13.0..=latest)]
+#[test]
+fn test_run_passes_with_hot_cold_splitting() {
+    Target::initialize_native(&InitializationConfig::default()).unwrap();
+
+    let context = Context::create();
+    let builder = context.create_builder();
+    let triple = TargetMachine::get_default_triple();
+    let machine = Target::from_triple(&triple)
+        .unwrap()
+        .create_target_machine(
+            &triple,
+            "generic",
+            "",
+            OptimizationLevel::None,
+            RelocMode::Default,
+            CodeModel::Default,
+        )
+        .unwrap();
+    let module = context.create_module("hot_cold");
+    let i32_type = context.i32_type();
+    let report_type = context
+        .void_type()
+        .fn_type(&[i32_type.into(), i32_type.into(), i32_type.into()], false);
+    let report = module.add_function("report", report_type, None);
+    let function = module.add_function("f", i32_type.fn_type(&[i32_type.into(), i32_type.into()], false), None);
+    let entry = context.append_basic_block(function, "entry");
+    let error_block = context.append_basic_block(function, "error");
+    let ok_block = context.append_basic_block(function, "ok");
+    let x = function.get_nth_param(0).unwrap().into_int_value();
+    let y = function.get_nth_param(1).unwrap().into_int_value();
+
+    for name in ["cold", "noreturn"] {
+        let kind_id = Attribute::get_named_enum_kind_id(name);
+
+        report.add_attribute(AttributeLoc::Function, context.create_enum_attribute(kind_id, 0));
+    }
+
+    builder.position_at_end(entry);
+
+    let is_zero = builder.build_int_compare(IntPredicate::EQ, x, i32_type.const_zero(), "is_zero");
+
+    builder.build_conditional_branch(is_zero, error_block, ok_block);
+    builder.position_at_end(error_block);
+
+    let a = builder.build_int_mul(y, i32_type.const_int(7, false), "a");
+    let b = builder.build_int_add(a, x, "b");
+    let d = builder.build_xor(b, i32_type.const_int(12, false), "d");
+
+    builder.build_call(report, &[a.into(), b.into(), d.into()], "");
+    builder.build_unreachable();
+    builder.position_at_end(ok_block);
+    builder.build_return(Some(&y));
+
+    let pass_options = PassBuilderOptions::create();
+
+    pass_options.set_hot_cold_splitting(true);
+    pass_options.set_cold_section(Some(".text.unlikely"));
+    module.run_passes("", &machine, pass_options).unwrap();
+
+    let cold_function = module.get_function("f.cold.1").unwrap();
+
+    assert_eq!(cold_function.get_section().unwrap().to_str(), Ok(".text.unlikely"));
+    assert!(function.get_section().is_none());
+    assert!(report.get_section().is_none());
+}
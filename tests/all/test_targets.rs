@@ -1,6 +1,7 @@
 use inkwell::context::Context;
 use inkwell::targets::{
-    ByteOrdering, CodeModel, FileType, InitializationConfig, RelocMode, Target, TargetData, TargetMachine, TargetTriple,
+    ByteOrdering, CodeModel, FileType, InitializationConfig, RelocMode, Target, TargetData, TargetFeatures,
+    TargetFeaturesError, TargetMachine, TargetTriple,
 };
 use inkwell::{AddressSpace, OptimizationLevel};
 
@@ -410,3 +411,148 @@ fn test_write_target_machine_to_memory_buffer() {
     assert!(string.contains("my_module"));
     assert!(string.contains(".section"));
 }
+
+#[test]
+fn test_target_features() {
+    Target::initialize_x86(&InitializationConfig::default());
+
+    let features = TargetFeatures::new()
+        .enable("sse2")
+        .disable("avx")
+        .enable("avx2")
+        .enable("avx");
+
+    assert_eq!(features.to_string(), "+sse2,+avx2,+avx");
+    assert_eq!(TargetFeatures::new().to_string(), "");
+
+    let triple = TargetTriple::create("x86_64-pc-linux-gnu");
+    let target = Target::from_triple(&triple).unwrap();
+    let err = TargetFeatures::new().enable("+sse2").validate(&target).unwrap_err();
+
+    assert_eq!(err, TargetFeaturesError::InvalidName("+sse2".to_owned()));
+    assert_eq!(err.to_string(), "Invalid target feature name: '+sse2'.");
+
+    #[cfg(not(feature = "llvm14-0"))]
+    assert_eq!(features.validate(&target), Err(TargetFeaturesError::CannotValidate));
+}
+
+#[llvm_versions(14.0..=latest)]
+#[test]
+fn test_target_features_validation() {
+    Target::initialize_x86(&InitializationConfig::default());
+
+    let triple = TargetTriple::create("x86_64-pc-linux-gnu");
+    let target = Target::from_triple(&triple).unwrap();
+    let features = TargetFeatures::new().enable("sse2").enable("avx2");
+
+    assert!(features.validate(&target).is_ok());
+
+    let features = TargetFeatures::new().enable("sse2").enable("simd128").disable("avx3");
+    let err = features.validate(&target).unwrap_err();
+
+    assert_eq!(
+        err,
+        TargetFeaturesError::Unrecognized(vec!["+simd128".to_owned(), "-avx3".to_owned()])
+    );
+    assert_eq!(err.to_string(), "Unrecognized target features: +simd128, -avx3.");
+
+    Target::initialize_webassembly(&InitializationConfig::default());
+
+    let triple = TargetTriple::create("wasm32-unknown-unknown");
+    let target = Target::from_triple(&triple).unwrap();
+    let features = TargetFeatures::new().enable("simd128").disable("tail-call");
+
+    assert!(features.validate(&target).is_ok());
+    assert!(TargetFeatures::new().enable("sse2").validate(&target).is_err());
+}
+
+#[test]
+fn test_write_wasm_object_to_memory_buffer() {
+    let context = Context::create();
+    let module = context.create_module("wasm");
+    let builder = context.create_builder();
+    let fn_type = context.i32_type().fn_type(&[], false);
+    let host_fn = module.add_function("host_fn", fn_type, None);
+    let run = module.add_function("run", fn_type, None);
+
+    host_fn.set_wasm_import("host", "get_value");
+    run.set_wasm_export_name("entry_point");
+
+    builder.position_at_end(context.append_basic_block(run, "entry"));
+
+    let value = builder
+        .build_call(host_fn, &[], "value")
+        .try_as_basic_value()
+        .left()
+        .unwrap();
+
+    builder.build_return(Some(&value));
+
+    let buffer = module
+        .write_wasm_object_to_memory_buffer(&TargetFeatures::new().enable("simd128"))
+        .unwrap();
+    let bytes = buffer.as_slice();
+    let contains = |needle: &[u8]| bytes.windows(needle.len()).any(|window| window == needle);
+
+    assert!(bytes.starts_with(b"\0asm"));
+    assert!(contains(b"host"));
+    assert!(contains(b"get_value"));
+    assert!(contains(b"entry_point"));
+
+    #[cfg(all(unix, feature = "llvm14-0"))]
+    {
+        let err = module
+            .write_wasm_object_to_memory_buffer(&TargetFeatures::new().enable("simd-128"))
+            .unwrap_err();
+
+        assert_eq!(err.to_str(), Ok("Unrecognized target features: +simd-128."));
+    }
+
+    module.set_triple(&TargetTriple::create("x86_64-pc-linux-gnu"));
+
+    let err = module
+        .write_wasm_object_to_memory_buffer(&TargetFeatures::new())
+        .unwrap_err();
+
+    assert_eq!(
+        err.to_str(),
+        Ok("The module's target triple is not a WebAssembly triple.")
+    );
+}
+
+#[test]
+fn test_address_spaces() {
+    let context = Context::create();
+    let target_data = TargetData::create("e-p:64:64-p2:32:32-p3:32:32-p5:32:32-A5-G1");
+
+    assert_eq!(
+        target_data.get_alloca_address_space(),
+        Some(AddressSpace::AMDGPU_PRIVATE)
+    );
+    assert_eq!(target_data.get_globals_address_space(), Some(AddressSpace::Global));
+    assert_eq!(target_data.get_program_address_space(), Some(AddressSpace::Generic));
+    assert_eq!(target_data.get_pointer_byte_size(Some(AddressSpace::AMDGPU_FLAT)), 8);
+    assert_eq!(target_data.get_pointer_byte_size(Some(AddressSpace::AMDGPU_REGION)), 4);
+
+    let region_ptr_type = context.i8_type().ptr_type(AddressSpace::AMDGPU_REGION);
+
+    assert_eq!(region_ptr_type.get_address_space(), AddressSpace::Region);
+    assert_eq!(
+        context
+            .ptr_sized_int_type(&target_data, Some(AddressSpace::Shared))
+            .get_bit_width(),
+        32
+    );
+
+    let default_data = TargetData::create("e-p:64:64");
+
+    assert_eq!(default_data.get_alloca_address_space(), Some(AddressSpace::Generic));
+    assert_eq!(default_data.get_globals_address_space(), Some(AddressSpace::Generic));
+
+    // Valid layouts may name address spaces AddressSpace has no variant for
+    let other_data = TargetData::create("e-p:64:64-A7-G100");
+
+    assert_eq!(other_data.get_alloca_address_space(), None);
+    assert_eq!(other_data.get_globals_address_space(), None);
+    assert_eq!(other_data.get_program_address_space(), Some(AddressSpace::Generic));
+}
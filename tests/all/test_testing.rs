@@ -0,0 +1,56 @@
+use inkwell::context::Context;
+use inkwell::testing::{assert_module_ir_eq, canonicalize_ir, diff_module_ir};
+
+#[test]
+fn test_canonicalize_ir() {
+    let canonical = canonicalize_ir(
+        "; some comment\nsource_filename = \"a.ll\"\n\ndefine i32 @id(i32 %x) {\n  ret i32 %x   ; identity\n}\n",
+    )
+    .unwrap();
+
+    assert_eq!(canonical, "\ndefine i32 @id(i32 %x) {\n  ret i32 %x\n}\n");
+    assert!(canonicalize_ir("define i32 @broken(").is_err());
+}
+
+#[test]
+fn test_diff_module_ir() {
+    let context = Context::create();
+    let module = context.create_module("golden");
+    let builder = context.create_builder();
+    let i32_type = context.i32_type();
+    let fn_type = i32_type.fn_type(&[i32_type.into()], false);
+    let function = module.add_function("inc", fn_type, None);
+    let entry = context.append_basic_block(function, "entry");
+
+    builder.position_at_end(entry);
+
+    let x = function.get_first_param().unwrap().into_int_value();
+    let sum = builder.build_int_add(x, i32_type.const_int(1, false), "sum");
+
+    builder.build_return(Some(&sum));
+
+    let expected = "
+        define i32 @inc(i32 %0) {
+        entry:
+          %sum = add i32 %0, 1
+          ret i32 %sum
+        }
+    ";
+
+    assert!(diff_module_ir(&module, expected).is_ok());
+    assert_module_ir_eq(&module, expected);
+
+    let diff = diff_module_ir(
+        &module,
+        "define i32 @inc(i32 %0) {\nentry:\n  %sum = add i32 %0, 2\n  ret i32 %sum\n}",
+    )
+    .unwrap_err();
+
+    assert!(diff.contains("-   %sum = add i32 %0, 2\n"));
+    assert!(diff.contains("+   %sum = add i32 %0, 1\n"));
+    assert!(diff.contains("    ret i32 %sum\n"));
+
+    let err = diff_module_ir(&module, "define i32 @inc(").unwrap_err();
+
+    assert!(err.starts_with("Could not parse expected IR"));
+}
@@ -113,6 +113,49 @@ fn test_function_type() {
     assert_eq!(fn_type.get_context(), context);
 }
 
+#[test]
+fn test_verify_inline_asm_constraints() {
+    let context = Context::create();
+    let void_type = context.void_type();
+    let i32_type = context.i32_type();
+    let i64_type = context.i64_type();
+    let i32_ptr_type = i32_type.ptr_type(AddressSpace::Generic);
+
+    let syscall_type = i64_type.fn_type(&[i64_type.into(), i64_type.into()], false);
+
+    assert!(syscall_type
+        .verify_inline_asm_constraints("=r,{rax},{rdi},~{memory}")
+        .is_ok());
+    assert!(syscall_type.verify_inline_asm_constraints("=&r,0,r").is_ok());
+    assert!(syscall_type.verify_inline_asm_constraints("=r,{rax}").is_err());
+    assert!(syscall_type
+        .verify_inline_asm_constraints("=r,{rax},~{memory},r")
+        .is_err());
+    assert!(syscall_type.verify_inline_asm_constraints("=r,{rax,r").is_err());
+    assert!(syscall_type.verify_inline_asm_constraints("=r,1,r").is_err());
+    assert!(syscall_type.verify_inline_asm_constraints("=r,,r").is_err());
+
+    let nop_type = void_type.fn_type(&[], false);
+
+    assert!(nop_type.verify_inline_asm_constraints("").is_ok());
+    assert!(nop_type
+        .verify_inline_asm_constraints("~{dirflag},~{fpsr},~{flags}")
+        .is_ok());
+    assert!(nop_type.verify_inline_asm_constraints("=r").is_err());
+
+    let store_type = void_type.fn_type(&[i32_ptr_type.into(), i32_type.into()], false);
+
+    assert!(store_type.verify_inline_asm_constraints("=*m,r").is_ok());
+    assert!(store_type.verify_inline_asm_constraints("r,=*m").is_err());
+
+    let pair_type = context.struct_type(&[i32_type.into(), i32_type.into()], false);
+    let cpuid_type = pair_type.fn_type(&[i32_type.into()], false);
+
+    assert!(cpuid_type.verify_inline_asm_constraints("={eax},={ebx},{eax}").is_ok());
+    assert!(cpuid_type.verify_inline_asm_constraints("={eax},{eax}").is_err());
+    assert!(i32_type.fn_type(&[], true).verify_inline_asm_constraints("=r").is_err());
+}
+
 #[test]
 fn test_sized_types() {
     unsafe { Context::get_global(sized_types) }
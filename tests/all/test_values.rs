@@ -1125,6 +1125,33 @@ fn test_function_value_to_global_to_pointer() {
     assert!(!fn_ptr_value.is_null());
     assert_eq!(fn_ptr_value.get_name().to_str(), Ok("my_func"));
     assert!(module.verify().is_ok());
+
+    assert_eq!(fn_value.get_dll_storage_class(), DLLStorageClass::Export);
+
+    fn_value.set_dll_storage_class(DLLStorageClass::Default);
+    fn_value.set_visibility(GlobalVisibility::Hidden);
+
+    assert_eq!(fn_global_value.get_dll_storage_class(), DLLStorageClass::Default);
+    assert_eq!(fn_global_value.get_visibility(), GlobalVisibility::Hidden);
+    assert!(module
+        .print_to_string()
+        .to_string()
+        .contains("define hidden void @my_func()"));
+
+    let imported_fn = module.add_function("imported_func", fn_type, None);
+
+    imported_fn.set_dll_storage_class(DLLStorageClass::Import);
+
+    assert!(module
+        .print_to_string()
+        .to_string()
+        .contains("declare dllimport void @imported_func()"));
+    assert!(module.verify().is_ok());
+
+    imported_fn.set_visibility(GlobalVisibility::Protected);
+
+    assert_eq!(imported_fn.get_visibility(), GlobalVisibility::Protected);
+    assert!(module.verify().is_err());
 }
 
 #[test]